@@ -0,0 +1,38 @@
+use change_calc::HashSet;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const LARGE_AMOUNT: u32 = 1_000_000;
+
+/// Only small coins so the inner loop runs many times for large amounts.
+fn few_small_coins() -> HashSet<u32> {
+    HashSet::from([5, 2, 1])
+}
+
+/// Many denominations like a real currency has.
+fn many_coins() -> HashSet<u32> {
+    HashSet::from([
+        50_000, 20_000, 10_000, 5_000, 2_000, 1_000, 500, 200, 100, 50, 20, 10, 5, 2, 1,
+    ])
+}
+
+fn bench_as_text_vs_numbers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calc_change");
+
+    for (name, coins) in [
+        ("few_small_coins", few_small_coins()),
+        ("many_coins", many_coins()),
+    ] {
+        group.bench_with_input(BenchmarkId::new("as_text", name), &coins, |b, coins| {
+            b.iter(|| change_calc::calc_change(black_box(LARGE_AMOUNT), coins))
+        });
+        group.bench_with_input(BenchmarkId::new("as_numbers", name), &coins, |b, coins| {
+            b.iter(|| change_calc::calc_change_vec(black_box(LARGE_AMOUNT), coins))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_as_text_vs_numbers);
+criterion_main!(benches);
@@ -0,0 +1,205 @@
+//! Functions in here search through every amount up to the wanted amount (dynamic programming).
+//! Unlike the greedy `calc_change` they find an exact change whenever one exists.
+use alloc::{vec, vec::Vec};
+
+use crate::{calc_change_vec, HashSet};
+
+/// Returns the largest amount up to the given amount which can be formed exactly from the coins.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([5, 10]);
+///
+/// assert_eq!(change_calc::closest_achievable(12, &coins), 10);
+/// ```
+pub fn closest_achievable(amount: u32, coin_units: &HashSet<u32>) -> u32 {
+    reachable_amounts(amount, coin_units)
+        .iter()
+        .rposition(|&reachable| reachable)
+        .unwrap_or(0) as u32
+}
+
+/// Returns true if the amount can be formed exactly, with as many coins of each unit as needed.
+/// ```
+/// use change_calc::HashSet;
+///
+/// assert!(change_calc::can_make_exact(6, &HashSet::from([1, 3, 4])));
+/// assert!(!change_calc::can_make_exact(3, &HashSet::from([2, 4])));
+/// ```
+pub fn can_make_exact(amount: u32, coin_units: &HashSet<u32>) -> bool {
+    reachable_amounts(amount, coin_units)[amount as usize]
+}
+
+/// Returns the fewest coins, largest first, which form the amount exactly.
+/// Returns None if the amount can not be formed exactly.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([1, 3, 4]);
+///
+/// // Greedy would return 4 1 1 here.
+/// assert_eq!(change_calc::calc_change_min(6, &coins), Some(vec![3, 3]));
+/// ```
+pub fn calc_change_min(amount: u32, coin_units: &HashSet<u32>) -> Option<Vec<u32>> {
+    change_from_table(&coins_table(amount, coin_units, Goal::FewestCoins), amount)
+}
+
+/// Returns the most coins, largest first, which form the amount exactly.
+/// Returns None if the amount can not be formed exactly.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([1, 5]);
+///
+/// assert_eq!(change_calc::calc_change_max_coins_count(7, &coins), Some(vec![1; 7]));
+/// ```
+pub fn calc_change_max_coins_count(amount: u32, coin_units: &HashSet<u32>) -> Option<Vec<u32>> {
+    change_from_table(&coins_table(amount, coin_units, Goal::MostCoins), amount)
+}
+
+/// Returns how many coins `calc_change_min` would return without building the change itself.
+pub fn min_coins_needed(amount: u32, coin_units: &HashSet<u32>) -> Option<u32> {
+    coins_table(amount, coin_units, Goal::FewestCoins)[amount as usize].map(|(count, _)| count)
+}
+
+/// Returns true if the greedy `calc_change` uses the fewest coins for every amount from 1 up to
+/// the given amount.
+/// Coin sets like {1, 3, 4} are not greedy optimal: greedy pays 6 as 4 1 1 instead of 3 3.
+/// ```
+/// use change_calc::HashSet;
+///
+/// assert!(change_calc::is_greedy_optimal(&HashSet::from([1, 5, 10, 25]), 100));
+/// assert!(!change_calc::is_greedy_optimal(&HashSet::from([1, 3, 4]), 10));
+/// ```
+pub fn is_greedy_optimal(coin_units: &HashSet<u32>, up_to: u32) -> bool {
+    let table = coins_table(up_to, coin_units, Goal::FewestCoins);
+
+    (1..=up_to).all(|amount| {
+        let fewest_coins = table[amount as usize].map(|(count, _)| count);
+        let greedy_coins = match calc_change_vec(amount, coin_units) {
+            (change, 0) => Some(change.len() as u32),
+            _ => None,
+        };
+
+        fewest_coins == greedy_coins
+    })
+}
+
+/// Tells if a table of coins holds the fewest or the most coins for every amount.
+#[derive(Debug, Clone, Copy)]
+enum Goal {
+    FewestCoins,
+    MostCoins,
+}
+
+/// Element at index i holds the fewest or most coins needed for the amount i
+/// and the last coin used for it.
+/// None means the amount i can not be formed exactly.
+fn coins_table(amount: u32, coin_units: &HashSet<u32>, goal: Goal) -> Vec<Option<(u32, u32)>> {
+    let mut table = vec![None; amount as usize + 1];
+    table[0] = Some((0, 0));
+
+    for current in 1..table.len() {
+        let candidates = coin_units
+            .iter()
+            .filter(|&&coin| coin != 0 && coin as usize <= current)
+            .filter_map(|&coin| table[current - coin as usize].map(|(count, _)| (count + 1, coin)));
+
+        table[current] = match goal {
+            Goal::FewestCoins => candidates.min(),
+            Goal::MostCoins => candidates.max(),
+        };
+    }
+
+    table
+}
+
+/// Walks back from the amount over the last coin used for every amount in the table.
+fn change_from_table(table: &[Option<(u32, u32)>], amount: u32) -> Option<Vec<u32>> {
+    let mut change = Vec::new();
+    let mut left_amount = amount as usize;
+
+    while left_amount > 0 {
+        let (_, last_coin) = table[left_amount]?;
+        change.push(last_coin);
+        left_amount -= last_coin as usize;
+    }
+
+    change.sort_unstable_by(|a, b| b.cmp(a));
+    Some(change)
+}
+
+/// Element at index i tells if the amount i can be formed exactly from the coins.
+fn reachable_amounts(amount: u32, coin_units: &HashSet<u32>) -> Vec<bool> {
+    let mut reachable = vec![false; amount as usize + 1];
+    reachable[0] = true;
+
+    for current in 1..reachable.len() {
+        reachable[current] = coin_units.iter().any(|&coin| {
+            let coin = coin as usize;
+            coin != 0 && coin <= current && reachable[current - coin]
+        });
+    }
+
+    reachable
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_return_closest_achievable_amount() {
+        assert_eq!(closest_achievable(12, &HashSet::from([5, 10])), 10);
+        assert_eq!(closest_achievable(12, &HashSet::from([5, 7])), 12);
+        assert_eq!(closest_achievable(4, &HashSet::from([5, 10])), 0);
+    }
+
+    #[test]
+    fn should_tell_if_exact_change_is_possible() {
+        assert!(can_make_exact(6, &HashSet::from([1, 3, 4])));
+        assert!(can_make_exact(0, &HashSet::from([2, 4])));
+        assert!(!can_make_exact(3, &HashSet::from([2, 4])));
+    }
+
+    #[test]
+    fn should_return_min_coins_needed() {
+        assert_eq!(min_coins_needed(6, &HashSet::from([1, 3, 4])), Some(2));
+        assert_eq!(min_coins_needed(0, &HashSet::from([1, 3, 4])), Some(0));
+        assert_eq!(min_coins_needed(3, &HashSet::from([2, 4])), None);
+    }
+
+    #[test]
+    fn should_tell_if_greedy_is_optimal() {
+        assert!(is_greedy_optimal(&HashSet::from([1, 5, 10, 25]), 100));
+        assert!(!is_greedy_optimal(&HashSet::from([1, 3, 4]), 100));
+        // Greedy pays 6 with 4 and leaves 2 although 3 3 is exact.
+        assert!(!is_greedy_optimal(&HashSet::from([3, 4]), 6));
+    }
+
+    #[test]
+    fn should_return_min_change() {
+        assert_eq!(
+            calc_change_min(6, &HashSet::from([1, 3, 4])),
+            Some(vec![3, 3])
+        );
+        assert_eq!(
+            calc_change_min(9, &HashSet::from([1, 3, 4])),
+            Some(vec![4, 4, 1])
+        );
+        assert_eq!(calc_change_min(3, &HashSet::from([2, 4])), None);
+    }
+
+    #[test]
+    fn should_return_change_with_most_coins() {
+        assert_eq!(
+            calc_change_max_coins_count(7, &HashSet::from([1, 5])),
+            Some(vec![1; 7])
+        );
+        assert_eq!(
+            calc_change_max_coins_count(11, &HashSet::from([3, 5])),
+            Some(vec![5, 3, 3])
+        );
+        assert_eq!(calc_change_max_coins_count(7, &HashSet::from([0, 2])), None);
+    }
+}
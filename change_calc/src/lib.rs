@@ -1,11 +1,90 @@
-use std::collections::HashSet;
+//! Calculates the change for an amount with given coins.
+//! Without the default feature std, the crate is `no_std` and only needs `alloc`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, hash::Hash, str::FromStr};
+use numeric::Numeric;
+
+#[cfg(not(feature = "std"))]
+pub use hashbrown::HashSet;
+/// The set of coins taken by the functions of this crate.
+/// With the feature std it is the one of std, otherwise the one of hashbrown.
+#[cfg(feature = "std")]
+pub use std::collections::HashSet;
+
+mod exact;
+mod spec;
+mod stream;
+mod wasm;
+
+pub use exact::{
+    calc_change_max_coins_count, calc_change_min, can_make_exact, closest_achievable,
+    is_greedy_optimal, min_coins_needed,
+};
+pub use spec::{calc_change_from_spec, parse_coins, SpecError};
+pub use stream::change_stream;
+pub use wasm::calc_change_wasm;
 // Derive generates a implementation for the trait debug during compilation. No inheritance. Debug allows printing an entity with all its field.
-#[derive(Debug)]
-pub struct ChangeWithLeft(pub String, pub u32);
+// PartialEq and Eq allow comparing with ==, Clone allows duplicating via clone().
+#[derive(Debug, PartialEq, Eq, Clone)]
+// The amount which is left has the same type as the amount and the coins, u32 if not given.
+pub struct ChangeWithLeft<N = u32>(pub String, pub N);
+
+impl<N> ChangeWithLeft<N> {
+    /// Collapses runs of the same coin into `count x coin` while keeping the descending order.
+    /// ```
+    /// use change_calc::ChangeWithLeft;
+    ///
+    /// let change = ChangeWithLeft("50 50 10 10 2".to_string(), 0);
+    ///
+    /// assert_eq!(change.as_grouped_string(), "2x50 2x10 1x2");
+    /// ```
+    pub fn as_grouped_string(&self) -> String {
+        let mut groups: Vec<(&str, u32)> = Vec::new();
+
+        for coin in self.0.split_whitespace() {
+            match groups.last_mut() {
+                Some((last_coin, count)) if *last_coin == coin => *count += 1,
+                _ => groups.push((coin, 1)),
+            }
+        }
+
+        groups
+            .iter()
+            .map(|(coin, count)| format!("{count}x{coin}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+/// Iterates over the coins of the change. The amount which is left is not part of the iteration,
+/// it stays reachable via the second field before the change is consumed.
+/// Words in the text which are no coins are skipped.
+impl<N: FromStr> IntoIterator for ChangeWithLeft<N> {
+    type Item = N;
+    type IntoIter = alloc::vec::IntoIter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let coins: Vec<N> = self
+            .0
+            .split_whitespace()
+            .filter_map(|coin| coin.parse().ok())
+            .collect();
+
+        coins.into_iter()
+    }
+}
+
 /// This example codex down here is compiled and the assert_eq! macro is validated during
 /// testing via cargo test.
 /// ```
-/// use std::collections::HashSet;
+/// use change_calc::HashSet;
 /// use change_calc::ChangeWithLeft;
 ///
 /// let amount = 98;
@@ -19,35 +98,208 @@ pub struct ChangeWithLeft(pub String, pub u32);
 /// assert_eq!(change, "50 10 10 10 10");
 /// assert_eq!(left, 8);
 /// ```
-pub fn calc_change(amount: u32, coin_units: &HashSet<u32>) -> ChangeWithLeft {
+/// Coins with the unit 0 are ignored.
+/// Any width of [`Numeric`] works for the amount and the coins, for example u128.
+/// The other functions of this crate only take u32.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([10u128.pow(20)]);
+///
+/// let change = change_calc::calc_change(3 * 10u128.pow(20) + 7, &coins);
+///
+/// assert_eq!(change.1, 7);
+/// ```
+pub fn calc_change<N>(amount: N, coin_units: &HashSet<N>) -> ChangeWithLeft<N>
+where
+    N: Numeric + Hash + fmt::Display,
+{
     let mut change_as_text = String::new();
     let mut left_amount = amount;
-    let mut unique_coins: Vec<_> = coin_units.iter().collect();
+    let mut unique_coins: Vec<_> = coin_units.iter().filter(|&&coin| coin != N::ZERO).collect();
 
     unique_coins.sort();
     unique_coins.reverse();
 
     for coin in unique_coins {
-        loop {
-            match try_sub_coin(left_amount, coin.clone()) {
-                Some(new_amount) => {
-                    left_amount = new_amount;
-                    change_as_text.push_str(&format!(" {coin}"));
-                }
-                None => break,
-            };
+        while let Some(new_amount) = try_sub_coin(left_amount, *coin) {
+            left_amount = new_amount;
+            change_as_text.push_str(&format!(" {coin}"));
         }
     }
 
     return ChangeWithLeft(change_as_text.trim().to_string(), left_amount);
 
-    fn try_sub_coin(amount: u32, coin: u32) -> Option<u32> {
-        if amount >= coin {
-            Some(amount - coin)
-        } else {
-            None
+    fn try_sub_coin<N: Numeric>(amount: N, coin: N) -> Option<N> {
+        amount.checked_sub(coin)
+    }
+}
+/// Same as `calc_change` but as if the coin `exclude` was not in the given coins.
+/// Useful if one coin has run out. The given coins are not changed.
+/// ```
+/// use change_calc::HashSet;
+/// use change_calc::ChangeWithLeft;
+///
+/// let coins = HashSet::from([50, 10, 5]);
+///
+/// let ChangeWithLeft(change, left) = change_calc::calc_change_excluding(65, &coins, 10);
+///
+/// assert_eq!(change, "50 5 5 5");
+/// assert_eq!(left, 0);
+/// ```
+pub fn calc_change_excluding(
+    amount: u32,
+    coin_units: &HashSet<u32>,
+    exclude: u32,
+) -> ChangeWithLeft {
+    let without_excluded: HashSet<u32> = coin_units
+        .iter()
+        .copied()
+        .filter(|&coin| coin != exclude)
+        .collect();
+    calc_change(amount, &without_excluded)
+}
+
+/// Same as `calc_change` but takes the coins as a slice.
+/// Duplicated coins are counted once and coins with the unit 0 are ignored.
+/// ```
+/// use change_calc::ChangeWithLeft;
+///
+/// let ChangeWithLeft(change, left) = change_calc::calc_change_slice(98, &[50, 10, 10, 0]);
+///
+/// assert_eq!(change, "50 10 10 10 10");
+/// assert_eq!(left, 8);
+/// ```
+pub fn calc_change_slice(amount: u32, coin_units: &[u32]) -> ChangeWithLeft {
+    let unique_coins: HashSet<u32> = coin_units
+        .iter()
+        .copied()
+        .filter(|&coin| coin != 0)
+        .collect();
+    calc_change(amount, &unique_coins)
+}
+
+/// Same greedy algorithm as `calc_change` but returns the coins as numbers instead of text.
+/// Coins with the unit 0 are ignored.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([50, 10]);
+///
+/// let (change, left) = change_calc::calc_change_vec(98, &coins);
+///
+/// assert_eq!(change, vec![50, 10, 10, 10, 10]);
+/// assert_eq!(left, 8);
+/// ```
+pub fn calc_change_vec(amount: u32, coin_units: &HashSet<u32>) -> (Vec<u32>, u32) {
+    let mut change = Vec::new();
+    let mut left_amount = amount;
+    let mut unique_coins: Vec<_> = coin_units
+        .iter()
+        .copied()
+        .filter(|&coin| coin != 0)
+        .collect();
+
+    unique_coins.sort();
+    unique_coins.reverse();
+
+    for coin in unique_coins {
+        while left_amount >= coin {
+            left_amount -= coin;
+            change.push(coin);
         }
     }
+
+    (change, left_amount)
+}
+/// Returns how often each coin is used in the change as (coin, count), largest coin first,
+/// and the amount which is left.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([50, 10]);
+///
+/// assert_eq!(
+///     change_calc::calc_change_histogram(98, &coins),
+///     (vec![(50, 1), (10, 4)], 8)
+/// );
+/// ```
+pub fn calc_change_histogram(amount: u32, coin_units: &HashSet<u32>) -> (Vec<(u32, u32)>, u32) {
+    let (change, left_amount) = calc_change_vec(amount, coin_units);
+    let mut histogram: Vec<(u32, u32)> = Vec::new();
+
+    for coin in change {
+        match histogram.last_mut() {
+            Some((last_coin, count)) if *last_coin == coin => *count += 1,
+            _ => histogram.push((coin, 1)),
+        }
+    }
+
+    (histogram, left_amount)
+}
+
+/// Amount of money in minor units, for example cents.
+/// The calculation stays with integers to avoid rounding errors of floats.
+/// Only the display shows the amount with a decimal point.
+/// ```
+/// use change_calc::Money;
+///
+/// assert_eq!(Money(238).to_string(), "2.38");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Money(pub u32);
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:02}", self.0 / 100, self.0 % 100)
+    }
+}
+
+/// Same as `calc_change` but for amount and coins in minor units.
+/// Every coin in the change is formatted with a decimal point.
+/// ```
+/// use change_calc::HashSet;
+/// use change_calc::Money;
+///
+/// let coins = HashSet::from([Money(100), Money(25)]);
+///
+/// let (change, left) = change_calc::calc_change_money(Money(230), &coins);
+///
+/// assert_eq!(change, "1.00 1.00 0.25");
+/// assert_eq!(left, Money(5));
+/// ```
+pub fn calc_change_money(amount: Money, coin_units: &HashSet<Money>) -> (String, Money) {
+    let in_minor_units: HashSet<u32> = coin_units.iter().map(|coin| coin.0).collect();
+    let (change, left_amount) = calc_change_vec(amount.0, &in_minor_units);
+    let change_as_text: Vec<_> = change
+        .into_iter()
+        .map(|coin| Money(coin).to_string())
+        .collect();
+
+    (change_as_text.join(" "), Money(left_amount))
+}
+
+/// Splits the amount into n parts which differ at most by 1 and sum up to the amount.
+/// The larger parts come first. Returns an empty vector if n is 0.
+/// ```
+/// assert_eq!(change_calc::distribute_evenly(10, 3), vec![4, 3, 3]);
+/// ```
+pub fn distribute_evenly(amount: u32, n: u32) -> Vec<u32> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let (part, rest) = (amount / n, amount % n);
+    (0..n)
+        .map(|index| if index < rest { part + 1 } else { part })
+        .collect()
+}
+
+/// Returns true if every amount can be paid exactly with the given coins.
+/// A coin with the unit 1 is the simplest way to make sure of this.
+/// Without it `calc_change` can return an amount which is left over.
+pub fn can_always_make_change(coin_units: &HashSet<u32>) -> bool {
+    coin_units.contains(&1)
 }
 // Only gets compiled during ant tests, (cargo test)
 #[cfg(test)]
@@ -64,6 +316,179 @@ mod testing {
         assert_change(90, &[50, 25], "50 25", 15);
     }
 
+    #[test]
+    fn should_group_identical_coins() {
+        let change = calc_change(122, &build_set(&[50, 25, 10, 5, 2, 1]));
+        assert_eq!(change.as_grouped_string(), "2x50 2x10 1x2");
+    }
+
+    #[test]
+    fn should_return_same_coins_as_text_variant() {
+        let coins = build_set(&[50, 25, 10, 5, 2, 1]);
+        let ChangeWithLeft(as_text, text_left) = calc_change(122, &coins);
+        let (as_numbers, numbers_left) = calc_change_vec(122, &coins);
+
+        let as_numbers_text: Vec<_> = as_numbers.iter().map(|coin| coin.to_string()).collect();
+        assert_eq!(as_text, as_numbers_text.join(" "));
+        assert_eq!(text_left, numbers_left);
+    }
+
+    #[test]
+    fn should_always_make_change_with_unit_coin() {
+        assert!(can_always_make_change(&build_set(&[1, 5, 10])));
+        assert!(!can_always_make_change(&build_set(&[5, 10])));
+    }
+
+    #[test]
+    fn should_return_same_change_for_slice_with_duplicates() {
+        let ChangeWithLeft(from_set, from_set_left) = calc_change(122, &build_set(&[50, 10, 2]));
+        let ChangeWithLeft(from_slice, from_slice_left) =
+            calc_change_slice(122, &[50, 10, 10, 2, 50, 0]);
+
+        assert_eq!(from_set, from_slice);
+        assert_eq!(from_set_left, from_slice_left);
+    }
+
+    #[test]
+    fn should_return_histogram_of_change() {
+        assert_eq!(
+            calc_change_histogram(98, &build_set(&[50, 10])),
+            (vec![(50, 1), (10, 4)], 8)
+        );
+        assert_eq!(calc_change_histogram(4, &build_set(&[50, 10])), (vec![], 4));
+    }
+
+    #[test]
+    fn should_return_change_for_money_with_decimal_point() {
+        let coins: HashSet<Money> = [100, 25, 10, 5, 1].into_iter().map(Money).collect();
+        let (change, left) = calc_change_money(Money(238), &coins);
+
+        assert_eq!(Money(238).to_string(), "2.38");
+        assert_eq!(change, "1.00 1.00 0.25 0.10 0.01 0.01 0.01");
+        assert_eq!(left, Money(0));
+    }
+
+    #[test]
+    fn should_pad_cents_of_money() {
+        assert_eq!(Money(5).to_string(), "0.05");
+        assert_eq!(Money(1200).to_string(), "12.00");
+    }
+
+    #[test]
+    fn should_distribute_evenly() {
+        assert_eq!(distribute_evenly(10, 3), vec![4, 3, 3]);
+        assert_eq!(distribute_evenly(9, 3), vec![3, 3, 3]);
+        assert_eq!(distribute_evenly(2, 4), vec![1, 1, 0, 0]);
+        assert_eq!(distribute_evenly(10, 0), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn should_iterate_over_coins_of_change() {
+        let change = calc_change(98, &build_set(&[50, 10]));
+        let left = change.1;
+
+        let mut paid = 0;
+        for coin in change {
+            paid += coin;
+        }
+
+        assert_eq!(paid, 90);
+        assert_eq!(left, 8);
+    }
+
+    #[test]
+    fn should_iterate_over_coins_of_u128_change() {
+        let coin = u128::from(u64::MAX) + 1;
+        let coins: Vec<u128> = calc_change(coin * 2 + 3, &HashSet::from([coin, 2]))
+            .into_iter()
+            .collect();
+
+        assert_eq!(coins, vec![coin, coin, 2]);
+    }
+
+    #[test]
+    fn should_compare_change_by_value() {
+        let change = calc_change(98, &build_set(&[50, 10]));
+
+        assert_eq!(change, ChangeWithLeft("50 10 10 10 10".into(), 8));
+        assert_eq!(change.clone(), change);
+        assert_ne!(change, ChangeWithLeft("50 10 10 10 10".into(), 7));
+    }
+
+    #[test]
+    fn should_return_change_without_excluded_coin() {
+        let coins = build_set(&[50, 10, 5]);
+
+        assert_eq!(
+            calc_change_excluding(65, &coins, 10),
+            ChangeWithLeft("50 5 5 5".into(), 0)
+        );
+        assert_eq!(
+            calc_change_excluding(65, &coins, 20),
+            ChangeWithLeft("50 10 5".into(), 0)
+        );
+        assert!(coins.contains(&10));
+    }
+
+    #[test]
+    fn should_ignore_zero_coin() {
+        assert_change(98, &[50, 10, 0], "50 10 10 10 10", 8);
+        assert_change(5, &[0], "", 5);
+    }
+
+    #[test]
+    fn should_ignore_zero_coin_when_excluding() {
+        assert_eq!(
+            calc_change_excluding(65, &build_set(&[50, 10, 5, 0]), 10),
+            ChangeWithLeft("50 5 5 5".into(), 0)
+        );
+    }
+
+    #[test]
+    fn should_ignore_zero_coin_for_vec() {
+        assert_eq!(
+            calc_change_vec(98, &build_set(&[50, 10, 0])),
+            (vec![50, 10, 10, 10, 10], 8)
+        );
+    }
+
+    #[test]
+    fn should_ignore_zero_coin_for_histogram() {
+        assert_eq!(
+            calc_change_histogram(98, &build_set(&[50, 10, 0])),
+            (vec![(50, 1), (10, 4)], 8)
+        );
+    }
+
+    #[test]
+    fn should_ignore_zero_coin_for_money() {
+        let coins = HashSet::from([Money(100), Money(25), Money(0)]);
+
+        assert_eq!(
+            calc_change_money(Money(230), &coins),
+            (String::from("1.00 1.00 0.25"), Money(5))
+        );
+    }
+
+    #[test]
+    fn should_return_same_change_for_u32_and_u128() {
+        let narrow = calc_change(238u32, &HashSet::from([50, 20, 10, 5, 2]));
+        let wide = calc_change(238u128, &HashSet::from([50, 20, 10, 5, 2]));
+
+        assert_eq!(narrow, ChangeWithLeft("50 50 50 50 20 10 5 2".into(), 1));
+        assert_eq!(wide, ChangeWithLeft("50 50 50 50 20 10 5 2".into(), 1));
+    }
+
+    #[test]
+    fn should_return_change_above_u64_for_u128() {
+        let coin = u128::from(u64::MAX) + 1;
+
+        assert_eq!(
+            calc_change(coin * 2 + 3, &HashSet::from([coin, 2])),
+            ChangeWithLeft(format!("{coin} {coin} 2"), 1)
+        );
+    }
+
     fn assert_change(amount: u32, coins: &[u32], expected_change: &str, expected_left: u32) {
         let ChangeWithLeft(actual_change, actual_left) = calc_change(amount, &build_set(coins));
         assert_eq!(actual_change, expected_change);
@@ -75,7 +500,7 @@ mod testing {
 
         numbers
             .iter()
-            .for_each(|to_insert| (_ = set.insert(*to_insert)));
+            .for_each(|to_insert| _ = set.insert(*to_insert));
 
         set
     }
@@ -1,9 +1,8 @@
-use change_calc;
-use std::collections::HashSet;
+use change_calc::HashSet;
 
 macro_rules! build_set {
     ($($k:expr),+) => {{
-       let mut _tmp_set = std::collections::HashSet::new();
+       let mut _tmp_set = HashSet::new();
        $(_tmp_set.insert($k);)+
         _tmp_set
     }};
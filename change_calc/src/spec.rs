@@ -0,0 +1,109 @@
+//! Parsing of the coins and the amount from text, for example from a command line argument.
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::{calc_change, ChangeWithLeft, HashSet};
+
+/// Reasons why a spec like "98:50,10" can not be parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SpecError {
+    /// No colon between the amount and the coins.
+    MissingColon,
+    /// Text which should be the amount is not a positive number.
+    InvalidAmount(String),
+    /// Text which should be a coin is not a positive number greater than 0.
+    InvalidCoin(String),
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecError::MissingColon => write!(f, "expected a colon between amount and coins"),
+            SpecError::InvalidAmount(amount) => write!(f, "amount {amount:?} is not a number"),
+            SpecError::InvalidCoin(coin) => {
+                write!(f, "coin {coin:?} is not a number greater than 0")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SpecError {}
+
+/// Parses coins separated by commas like "50,20,10".
+/// ```
+/// use change_calc::HashSet;
+///
+/// assert_eq!(change_calc::parse_coins("50, 20,10"), Ok(HashSet::from([50, 20, 10])));
+/// ```
+pub fn parse_coins(coins: &str) -> Result<HashSet<u32>, SpecError> {
+    coins
+        .split(',')
+        .map(|coin| match coin.trim().parse() {
+            Ok(0) | Err(_) => Err(SpecError::InvalidCoin(coin.to_string())),
+            Ok(coin) => Ok(coin),
+        })
+        .collect()
+}
+
+/// Computes the change for a text like "238:50,20,10,5,2".
+/// The amount is in front of the colon and the coins after it.
+/// ```
+/// use change_calc::ChangeWithLeft;
+///
+/// let ChangeWithLeft(change, left) = change_calc::calc_change_from_spec("98:50,10").unwrap();
+///
+/// assert_eq!(change, "50 10 10 10 10");
+/// assert_eq!(left, 8);
+/// ```
+pub fn calc_change_from_spec(spec: &str) -> Result<ChangeWithLeft, SpecError> {
+    let (amount, coins) = spec.split_once(':').ok_or(SpecError::MissingColon)?;
+    let amount: u32 = amount
+        .trim()
+        .parse()
+        .map_err(|_| SpecError::InvalidAmount(amount.to_string()))?;
+    let coins = parse_coins(coins)?;
+
+    Ok(calc_change(amount, &coins))
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_calc_change_from_valid_spec() {
+        assert_eq!(
+            calc_change_from_spec("238:50,20,10,5,2"),
+            Ok(ChangeWithLeft("50 50 50 50 20 10 5 2".into(), 1))
+        );
+    }
+
+    #[test]
+    fn should_report_missing_colon() {
+        assert_eq!(calc_change_from_spec("238"), Err(SpecError::MissingColon));
+    }
+
+    #[test]
+    fn should_report_invalid_amount() {
+        assert_eq!(
+            calc_change_from_spec("-2:50,20"),
+            Err(SpecError::InvalidAmount("-2".into()))
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_coins() {
+        assert_eq!(
+            calc_change_from_spec("238:50,a"),
+            Err(SpecError::InvalidCoin("a".into()))
+        );
+        assert_eq!(
+            calc_change_from_spec("238:50,0"),
+            Err(SpecError::InvalidCoin("0".into()))
+        );
+        assert_eq!(
+            calc_change_from_spec("238:"),
+            Err(SpecError::InvalidCoin("".into()))
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! `ChangeStream` yields the coins of the greedy change lazily, one coin per call of `next`.
+use alloc::vec::Vec;
+
+use crate::HashSet;
+
+/// Yields the coins of the greedy change one after another, largest first.
+/// Nothing is computed in advance so pulling can be stopped at any coin.
+/// ```
+/// use change_calc::HashSet;
+///
+/// let coins = HashSet::from([50, 10]);
+///
+/// let first_two: Vec<_> = change_calc::change_stream(98, &coins).take(2).collect();
+///
+/// assert_eq!(first_two, vec![50, 10]);
+/// ```
+pub fn change_stream(amount: u32, coin_units: &HashSet<u32>) -> impl Iterator<Item = u32> {
+    let mut descending_coins: Vec<u32> = coin_units.iter().copied().collect();
+    descending_coins.sort_unstable_by(|a, b| b.cmp(a));
+
+    ChangeStream {
+        left_amount: amount,
+        descending_coins,
+        current_coin: 0,
+    }
+}
+
+struct ChangeStream {
+    left_amount: u32,
+    descending_coins: Vec<u32>,
+    /// Index of the largest coin which could still fit into the left amount.
+    current_coin: usize,
+}
+
+impl Iterator for ChangeStream {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&coin) = self.descending_coins.get(self.current_coin) {
+            if coin != 0 && self.left_amount >= coin {
+                self.left_amount -= coin;
+                return Some(coin);
+            }
+
+            self.current_coin += 1;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::calc_change_vec;
+
+    #[test]
+    fn should_yield_same_coins_as_vec_variant() {
+        let coins = HashSet::from([50, 25, 10, 5, 2]);
+        for amount in [0, 1, 98, 122, 238] {
+            let streamed: Vec<_> = change_stream(amount, &coins).collect();
+            let (expected, _) = calc_change_vec(amount, &coins);
+
+            assert_eq!(streamed, expected, "Not same coins for amount {amount}");
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Entry point for JavaScript. With the feature wasm it is exported via `wasm-bindgen`,
+//! without it the function is a normal one of this crate.
+use alloc::{format, string::String, vec::Vec};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{calc_change_vec, HashSet};
+
+/// Same as `calc_change_slice` but returns the change as JSON with the coins as numbers,
+/// largest coin first, and the amount which is left.
+/// Duplicated coins are counted once and coins with the unit 0 are ignored.
+/// ```
+/// let json = change_calc::calc_change_wasm(98, &[50, 10, 10, 0]);
+///
+/// assert_eq!(json, r#"{"change":[50,10,10,10,10],"left":8}"#);
+/// ```
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn calc_change_wasm(amount: u32, coins: &[u32]) -> String {
+    let unique_coins: HashSet<u32> = coins.iter().copied().filter(|&coin| coin != 0).collect();
+    let (change, left) = calc_change_vec(amount, &unique_coins);
+    let change: Vec<String> = change.iter().map(|coin| format!("{coin}")).collect();
+
+    format!(r#"{{"change":[{}],"left":{left}}}"#, change.join(","))
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_return_change_and_left_as_json() {
+        assert_eq!(
+            calc_change_wasm(238, &[50, 20, 10, 5, 2]),
+            r#"{"change":[50,50,50,50,20,10,5,2],"left":1}"#
+        );
+    }
+
+    #[test]
+    fn should_return_empty_change_without_coins() {
+        assert_eq!(calc_change_wasm(7, &[]), r#"{"change":[],"left":7}"#);
+        assert_eq!(calc_change_wasm(0, &[0]), r#"{"change":[],"left":0}"#);
+    }
+}
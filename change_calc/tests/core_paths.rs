@@ -0,0 +1,23 @@
+//! Runs with std and with `--no-default-features`, where the library is `no_std`.
+use change_calc::{calc_change, calc_change_min, parse_coins, ChangeWithLeft, HashSet};
+
+#[test]
+fn should_calc_change_with_crate_set() {
+    let coins: HashSet<u32> = [50, 20, 10].into_iter().collect();
+
+    assert_eq!(
+        calc_change(238, &coins),
+        ChangeWithLeft("50 50 50 50 20 10".to_string(), 8)
+    );
+    assert_eq!(calc_change_min(60, &coins), Some(vec![50, 10]));
+}
+
+#[test]
+fn should_parse_coins_into_crate_set() {
+    let coins = parse_coins("5, 2").unwrap();
+
+    assert_eq!(
+        calc_change(9, &coins).into_iter().collect::<Vec<_>>(),
+        vec![5, 2, 2]
+    );
+}
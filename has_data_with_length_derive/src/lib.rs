@@ -0,0 +1,67 @@
+//! Derive macro for the trait HasDataWithLength of the crate impl_traits_on_extern_types.
+//! Use it via the reexport in impl_traits_on_extern_types.
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Field, Fields};
+
+/// Annotate one field with `#[length]` and one with `#[data]`.
+/// The length field must convert into usize without loss like usize, u16 or u8 and
+/// the data field must implement Display. Other length types like i32 do not compile.
+#[proc_macro_derive(HasDataWithLength, attributes(length, data))]
+pub fn derive_has_data_with_length(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match implement(&input) {
+        Ok(implementation) => implementation.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn implement(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(input, "expected named fields")),
+        },
+        _ => return Err(Error::new_spanned(input, "expected a struct")),
+    };
+
+    let annotated = |attribute: &str| -> Result<&Field, Error> {
+        let mut found = fields
+            .iter()
+            .filter(|field| field.attrs.iter().any(|a| a.path().is_ident(attribute)));
+        match (found.next(), found.next()) {
+            (Some(field), None) => Ok(field),
+            (Some(_), Some(second)) => Err(Error::new_spanned(
+                second,
+                format!("only one field can be annotated with #[{attribute}]"),
+            )),
+            (None, _) => Err(Error::new(
+                Span::call_site(),
+                format!("one field must be annotated with #[{attribute}]"),
+            )),
+        }
+    };
+    let length_field = annotated("length")?;
+    let length = &length_field.ident;
+    let data = &annotated("data")?.ident;
+    // Spanned to the type, so a length type without a lossless conversion is reported there.
+    let length_into_usize = quote_spanned! {length_field.ty.span()=>
+        ::std::convert::Into::<usize>::into(self.#length)
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::impl_traits_on_extern_types::HasDataWithLength
+            for #name #type_generics #where_clause
+        {
+            type Data = ::std::string::String;
+            fn get_data_with_length(&self) -> (usize, Self::Data) {
+                (#length_into_usize, ::std::string::ToString::to_string(&self.#data))
+            }
+        }
+    })
+}
@@ -0,0 +1,71 @@
+/// Hops over the elements of another iterator depending on a predicate.
+/// Elements for which the predicate is true are inside and returned,
+/// all others are outside and skipped.
+///
+/// Created by [`crate::HopperExt::hopp_while`].
+#[derive(Clone)]
+pub struct HopperBy<I, F> {
+    iter: I,
+    is_inside: F,
+}
+
+impl<I, F> HopperBy<I, F> {
+    pub fn new(iter: I, is_inside: F) -> Self {
+        HopperBy { iter, is_inside }
+    }
+}
+
+/// The predicate is not printed since closures do not implement Debug.
+impl<I: std::fmt::Debug, F> std::fmt::Debug for HopperBy<I, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HopperBy")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> Iterator for HopperBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.by_ref().find(|next| (self.is_inside)(next))
+    }
+
+    /// Every element could be outside.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F> DoubleEndedIterator for HopperBy<I, F>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.by_ref().rfind(|next| (self.is_inside)(next))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::HopperExt;
+
+    #[test]
+    fn should_yield_only_inside_elements() {
+        let hopped: Vec<_> = (1..10).hopp_while(|x| x % 2 == 0).collect();
+
+        assert_eq!(hopped, vec![2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn should_yield_inside_elements_from_back() {
+        let hopped: Vec<_> = (1..10).hopp_while(|x| *x > 6).rev().collect();
+
+        assert_eq!(hopped, vec![9, 8, 7]);
+    }
+}
@@ -0,0 +1,105 @@
+use std::ops::RangeInclusive;
+
+/// Hops over the elements of another iterator with irregular durations.
+/// The length of every take and skip window is drawn from a range
+/// by a small deterministic pseudo random number generator.
+/// The same seed always results in the same windows.
+///
+/// Created by [`crate::HopperExt::hopp_jitter`].
+#[derive(Clone, Debug)]
+pub struct JitterHopper<I> {
+    take_range: RangeInclusive<usize>,
+    skip_range: RangeInclusive<usize>,
+    left_to_take: usize,
+    rng_state: u64,
+    iter: I,
+}
+
+impl<I> JitterHopper<I> {
+    /// Starts with a take window.
+    ///
+    /// # Panics
+    /// If a range is empty or includes zero.
+    pub fn new(
+        iter: I,
+        take_range: RangeInclusive<usize>,
+        skip_range: RangeInclusive<usize>,
+        seed: u64,
+    ) -> Self {
+        for range in [&take_range, &skip_range] {
+            if range.is_empty() || *range.start() == 0 {
+                panic!("Ranges for take and skip must not be empty or include zero !");
+            }
+        }
+
+        let mut hopper = JitterHopper {
+            take_range,
+            skip_range,
+            left_to_take: 0,
+            rng_state: seed,
+            iter,
+        };
+        hopper.left_to_take = hopper.draw(hopper.take_range.clone());
+        hopper
+    }
+
+    /// Draws a number within the range via splitmix64.
+    fn draw(&mut self, range: RangeInclusive<usize>) -> usize {
+        self.rng_state = self.rng_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut random = self.rng_state;
+        random = (random ^ (random >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        random = (random ^ (random >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        random ^= random >> 31;
+
+        let (start, end) = (*range.start() as u64, *range.end() as u64);
+        let offset = match (end - start).checked_add(1) {
+            Some(span) => random % span,
+            None => random,
+        };
+        (start + offset) as usize
+    }
+}
+
+impl<I> Iterator for JitterHopper<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left_to_take == 0 {
+            let skip = self.draw(self.skip_range.clone());
+            if skip > 0 {
+                self.iter.nth(skip - 1)?;
+            }
+            self.left_to_take = self.draw(self.take_range.clone());
+        }
+
+        self.left_to_take -= 1;
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::HopperExt;
+
+    #[test]
+    fn should_hop_deterministic_for_seed() {
+        let hopped: Vec<_> = (1..40).hopp_jitter(1..=3, 2..=4, 42).collect();
+        let again: Vec<_> = (1..40).hopp_jitter(1..=3, 2..=4, 42).collect();
+
+        assert_eq!(hopped, again);
+        assert_eq!(
+            hopped,
+            vec![1, 2, 6, 9, 10, 13, 14, 19, 20, 25, 26, 27, 31, 32, 33, 37, 38, 39]
+        );
+    }
+
+    #[test]
+    fn should_hop_like_hopper_for_single_value_ranges() {
+        let hopped: Vec<_> = (1..20).hopp_jitter(2..=2, 3..=3, 7).collect();
+
+        assert_eq!(hopped, (1..20).hopp(2, 3).collect::<Vec<_>>());
+    }
+}
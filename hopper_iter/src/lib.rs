@@ -0,0 +1,764 @@
+//! Iterator which hops over the elements of another iterator.
+//! It takes a number of elements, then skips a number of elements and so on.
+//! ```
+//! use hopper_iter::HopperExt;
+//!
+//! let hopped: Vec<_> = (1..20).hopp(2, 3).collect();
+//!
+//! assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+//! ```
+
+use std::{error::Error, fmt::Display, iter::Peekable, ops::RangeInclusive};
+
+mod hopper_by;
+mod jitter;
+mod windows;
+
+pub use hopper_by::HopperBy;
+pub use jitter::JitterHopper;
+pub use windows::HopperWindows;
+
+/// Reason why a [`Hopper`] can not be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HopperError {
+    /// Take or skip duration is zero.
+    ZeroDuration,
+    /// More elements should be taken than there are in a window of a ratio.
+    TakeExceedsWindow { take: usize, window: usize },
+}
+
+impl Display for HopperError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HopperError::ZeroDuration => write!(f, "take and skip must not be zero"),
+            HopperError::TakeExceedsWindow { take, window } => {
+                write!(f, "can not take {take} of every {window} elements")
+            }
+        }
+    }
+}
+
+impl Error for HopperError {}
+
+/// Whether the next pull of a [`Hopper`] returns an element or skips elements first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HopPhase {
+    /// The next element is returned.
+    Inside,
+    /// Elements are skipped before the next one is returned.
+    Outside,
+}
+
+/// In Rust custom iterator are sturcts which take another iterator.
+/// Custom iterator implement the trait Iterator
+/// It returns `take` elements, then skips `skip` elements and so on.
+#[derive(Clone, Debug)]
+pub struct Hopper<I> {
+    take: usize,
+    current_step: usize,
+    skip: usize,
+    start: bool,
+    cycles: Option<usize>,
+    cycles_left: Option<usize>,
+    iter: I,
+}
+
+impl<I> Hopper<I> {
+    /// Takes `take` elements, then skips `skip` elements.
+    /// If `start` is false, it begins with skipping instead of taking.
+    ///
+    /// # Panics
+    /// If `take` or `skip` is zero.
+    pub fn new(iter: I, take: usize, skip: usize, start: bool) -> Self {
+        match Self::try_new(iter, take, skip, start) {
+            Ok(hopper) => hopper,
+            Err(error) => panic!("{error} !"),
+        }
+    }
+
+    /// Like [`Hopper::new`] but returns [`HopperError::ZeroDuration`] instead of panicking.
+    pub fn try_new(iter: I, take: usize, skip: usize, start: bool) -> Result<Self, HopperError> {
+        if take == 0 || skip == 0 {
+            return Err(HopperError::ZeroDuration);
+        }
+
+        Ok(Self::new_lenient(iter, take, skip, start))
+    }
+
+    /// Like [`Hopper::new`] but accepts zero durations.
+    /// A `take` of zero returns nothing and a `skip` of zero returns everything.
+    /// If both are zero, nothing is returned.
+    pub fn new_lenient(iter: I, take: usize, skip: usize, start: bool) -> Self {
+        Hopper {
+            iter,
+            take,
+            skip,
+            start,
+            cycles: None,
+            cycles_left: None,
+            current_step: Self::initial_step(take, start),
+        }
+    }
+
+    /// Stops after at most `cycles` take windows, even if the inner iterator has more elements.
+    pub fn with_cycles(mut self, cycles: usize) -> Self {
+        self.cycles = Some(cycles);
+        self.cycles_left = Some(cycles);
+        self
+    }
+
+    /// Restarts the take/skip pattern as if this hopper was freshly constructed.
+    /// The inner iterator is not rewound: the restarted pattern applies to its remaining elements.
+    /// A limit of take windows via [`Hopper::with_cycles`] is restored too.
+    pub fn reset(&mut self) {
+        self.current_step = Self::initial_step(self.take, self.start);
+        self.cycles_left = self.cycles;
+    }
+
+    /// Tells if the next call of next returns the next inner element or skips first.
+    pub fn phase(&self) -> HopPhase {
+        let no_cycles_left = self.starts_window() && self.cycles_left == Some(0);
+        if self.take > 0 && !no_cycles_left && (self.current_step < self.take || self.skip == 0) {
+            HopPhase::Inside
+        } else {
+            HopPhase::Outside
+        }
+    }
+
+    /// How many elements are returned or skipped, depending on [`Hopper::phase`],
+    /// before the phase flips.
+    /// Returns `usize::MAX` if the hopper never returns an element again.
+    pub fn remaining_in_window(&self) -> usize {
+        match self.phase() {
+            HopPhase::Inside if self.current_step < self.take => self.take - self.current_step,
+            HopPhase::Inside => self.take,
+            HopPhase::Outside if self.take == 0 || self.cycles_left == Some(0) => usize::MAX,
+            HopPhase::Outside => self.skip,
+        }
+    }
+
+    /// True if the next returned element is the first one of a take window.
+    fn starts_window(&self) -> bool {
+        self.current_step == 0 || self.current_step >= self.take
+    }
+
+    fn initial_step(take: usize, start: bool) -> usize {
+        if start {
+            0
+        } else {
+            // Any step at or past take skips first. Saturating keeps usize::MAX as take valid.
+            take.saturating_add(1)
+        }
+    }
+
+    /// Returns how many of the next elements of the inner iterator are returned by this hopper.
+    fn count_returned_from(&self, inner_len: usize) -> usize {
+        let count = self.count_hopped_from(inner_len);
+        match self.cycles_left {
+            Some(cycles_left) => {
+                let left_in_current_take = if self.starts_window() {
+                    0
+                } else {
+                    self.take - self.current_step
+                };
+                count
+                    .min(left_in_current_take.saturating_add(cycles_left.saturating_mul(self.take)))
+            }
+            None => count,
+        }
+    }
+
+    /// Like [`Hopper::count_returned_from`] but ignores a limit of take windows.
+    fn count_hopped_from(&self, inner_len: usize) -> usize {
+        let (take, skip) = (self.take, self.skip);
+        if take == 0 {
+            return 0;
+        }
+
+        let left_in_current_take = take.saturating_sub(self.current_step);
+
+        if inner_len <= left_in_current_take {
+            return inner_len;
+        }
+
+        let after_current_take = inner_len - left_in_current_take;
+        let Some(hop) = take.checked_add(skip) else {
+            // A hop longer than usize::MAX is never completed by the remaining elements.
+            return left_in_current_take + after_current_take.saturating_sub(skip);
+        };
+        let (full_hops, rest) = (after_current_take / hop, after_current_take % hop);
+        left_in_current_take + (full_hops * take) + rest.saturating_sub(skip)
+    }
+}
+
+impl<J> Hopper<Peekable<J>>
+where
+    J: Iterator,
+{
+    /// Returns the element which the next call of next would return without returning it.
+    /// Elements to skip before it are consumed already.
+    /// Because of that [`Hopper::phase`] is [`HopPhase::Inside`] afterwards.
+    /// ```
+    /// use hopper_iter::HopperExt;
+    ///
+    /// let mut hopper = (1..20).peekable().hopp_past_start(2, 3);
+    ///
+    /// assert_eq!(hopper.peek(), Some(&4));
+    /// assert_eq!(hopper.next(), Some(4));
+    /// ```
+    pub fn peek(&mut self) -> Option<&J::Item> {
+        if self.take == 0 || (self.starts_window() && self.cycles_left == Some(0)) {
+            return None;
+        }
+
+        if self.current_step >= self.take {
+            if self.skip > 0 {
+                self.iter.nth(self.skip - 1)?;
+            }
+            self.current_step = 0;
+        }
+
+        self.iter.peek()
+    }
+}
+
+impl<I> Iterator for Hopper<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    /// Logic to return an element from an iterator.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.take == 0 {
+            return None;
+        }
+
+        if self.starts_window() {
+            match self.cycles_left {
+                Some(0) => return None,
+                Some(ref mut cycles_left) => *cycles_left -= 1,
+                None => (),
+            }
+        }
+
+        if self.current_step >= self.take {
+            if self.skip > 0 {
+                self.iter.nth(self.skip - 1)?;
+            }
+            self.current_step = 1;
+        } else {
+            self.current_step += 1;
+        }
+
+        self.iter.next()
+    }
+
+    /// Jumps over the skipped elements and the elements within a take window
+    /// via nth of the inner iterator instead of going through them one by one.
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        while n > 0 {
+            if self.take == 0 {
+                return None;
+            }
+
+            if self.starts_window() {
+                self.next()?;
+                n -= 1;
+                continue;
+            }
+
+            let jump = n.min(self.take - self.current_step);
+            self.iter.nth(jump - 1)?;
+            self.current_step += jump;
+            n -= jump;
+        }
+
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            self.count_returned_from(lower),
+            upper.map(|upper| self.count_returned_from(upper)),
+        )
+    }
+}
+
+/// With a known number of inner elements the number of returned elements is known too.
+impl<I> ExactSizeIterator for Hopper<I> where I: ExactSizeIterator {}
+
+/// The hops stay aligned to the front: from the back exactly those elements are returned,
+/// in reverse order, which would be returned from the front.
+/// For this the number of inner elements must be known.
+impl<I> DoubleEndedIterator for Hopper<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.take == 0 {
+            return None;
+        }
+
+        loop {
+            let inner_len = self.iter.len();
+            if inner_len == 0 {
+                return None;
+            }
+
+            let is_last_returned =
+                self.count_returned_from(inner_len) > self.count_returned_from(inner_len - 1);
+            let last = self.iter.next_back()?;
+            if is_last_returned {
+                return Some(last);
+            }
+        }
+    }
+}
+
+/// Supertrait of trait Iterator with default implementations only.
+/// These default implementations as function will be used on iterator.
+/// Bring it into scope to call them on any iterator:
+/// ```
+/// use hopper_iter::HopperExt;
+///
+/// let hopped: Vec<_> = vec!['a', 'b', 'c', 'd'].into_iter().hopp(1, 2).collect();
+///
+/// assert_eq!(hopped, vec!['a', 'd']);
+/// ```
+pub trait HopperExt: Iterator {
+    /// Usage: (1..20).hopp(2, 3)
+    /// take: 2 print up to 2 numbers.
+    /// skip: 3 After 2 numbers skip the next 3 numbers.
+    /// output: [1, 2, 6, 7, 11, 12, 16, 17]
+    fn hopp(self, take: usize, skip: usize) -> Hopper<Self>
+    where
+        Self: Sized,
+    {
+        Hopper::new(self, take, skip, true)
+    }
+
+    /// Like [`HopperExt::hopp`] but stops after `cycles` take windows.
+    /// Usage: (1..1000).hopp_times(2, 3, 2)
+    /// output: [1, 2, 6, 7]
+    fn hopp_times(self, take: usize, skip: usize, cycles: usize) -> Hopper<Self>
+    where
+        Self: Sized,
+    {
+        Hopper::new(self, take, skip, true).with_cycles(cycles)
+    }
+
+    /// Like [`HopperExt::hopp`] but every window length is drawn from its range.
+    /// The same seed results in the same output.
+    /// Usage: (1..40).hopp_jitter(1..=3, 2..=4, 42)
+    fn hopp_jitter(
+        self,
+        take_range: RangeInclusive<usize>,
+        skip_range: RangeInclusive<usize>,
+        seed: u64,
+    ) -> JitterHopper<Self>
+    where
+        Self: Sized,
+    {
+        JitterHopper::new(self, take_range, skip_range, seed)
+    }
+
+    /// Like [`HopperExt::hopp`] but returns every take window as one vector.
+    /// Usage: (1..20).hopp_windows(2, 3)
+    /// output: [[1, 2], [6, 7], [11, 12], [16, 17]]
+    fn hopp_windows(self, take: usize, skip: usize) -> HopperWindows<Self>
+    where
+        Self: Sized,
+    {
+        HopperWindows::new(self.hopp(take, skip))
+    }
+
+    /// Returns the first `n` of every `m` elements.
+    /// Fails if `n` is zero or greater than `m`.
+    /// Usage: (1..=10).sample_ratio(1, 3)
+    /// output: [1, 4, 7, 10]
+    fn sample_ratio(self, n: usize, m: usize) -> Result<Hopper<Self>, HopperError>
+    where
+        Self: Sized,
+    {
+        if n == 0 {
+            return Err(HopperError::ZeroDuration);
+        }
+        if n > m {
+            return Err(HopperError::TakeExceedsWindow { take: n, window: m });
+        }
+
+        Ok(Hopper::new_lenient(self, n, m - n, true))
+    }
+
+    /// Hopps and collects in one call.
+    /// Usage: let hopped: Vec<_> = (1..20).hopp_collect(2, 3);
+    /// output: [1, 2, 6, 7, 11, 12, 16, 17]
+    fn hopp_collect<B>(self, take: usize, skip: usize) -> B
+    where
+        Self: Sized,
+        B: FromIterator<Self::Item>,
+    {
+        self.hopp(take, skip).collect()
+    }
+
+    /// Like [`HopperExt::hopp`] but returns an error for a zero `take` or `skip`.
+    fn try_hopp(self, take: usize, skip: usize) -> Result<Hopper<Self>, HopperError>
+    where
+        Self: Sized,
+    {
+        Hopper::try_new(self, take, skip, true)
+    }
+
+    /// Instead of fixed counts the predicate decides per element whether it is returned.
+    /// Usage: (1..10).hopp_while(|x| x % 2 == 0)
+    /// output: [2, 4, 6, 8]
+    fn hopp_while<F>(self, is_inside: F) -> HopperBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        HopperBy::new(self, is_inside)
+    }
+
+    /// Like [`HopperExt::hopp`] but starts with skipping `skip` elements.
+    /// Usage: (1..20).hopp_past_start(2, 3)
+    /// output: [4, 5, 9, 10, 14, 15, 19]
+    fn hopp_past_start(self, take: usize, skip: usize) -> Hopper<Self>
+    where
+        Self: Sized,
+    {
+        Hopper::new(self, take, skip, false)
+    }
+}
+
+/// By implementing HopperExt for Iterator, structs which implement the iterator trait get access
+/// to the default implementation of the trait HopperExt.
+/// This is comparable to the concept of extension methods in c#.
+/// I: Iterator is generic. This means all structs which implement trait Iterator,  implement
+/// trait HopperExt now.
+impl<I: Iterator> HopperExt for I {}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_take_then_skip() {
+        let hopped: Vec<_> = (1..20).hopp(2, 3).collect();
+        assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+
+        let hopped: Vec<_> = (1..20).hopp(3, 2).collect();
+        assert_eq!(hopped, vec![1, 2, 3, 6, 7, 8, 11, 12, 13, 16, 17, 18]);
+    }
+
+    #[test]
+    fn should_not_overflow_at_usize_max() {
+        let hopped: Vec<_> = (0..10).hopp_past_start(usize::MAX, 1).collect();
+        assert_eq!(hopped, (1..10).collect::<Vec<_>>());
+
+        let half = usize::MAX / 2 + 1;
+        let hopper = (0..).hopp(half, half);
+        assert_eq!(hopper.size_hint(), (half, None));
+        assert_eq!(hopper.take(3).collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let hopper = (0..10).hopp(usize::MAX, usize::MAX);
+        assert_eq!(hopper.len(), 10);
+        assert_eq!(hopper.rev().take(2).collect::<Vec<_>>(), vec![9, 8]);
+        assert_eq!((0..10).hopp(1, usize::MAX).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn should_skip_then_take_past_start() {
+        let hopped: Vec<_> = (1..20).hopp_past_start(2, 3).collect();
+        assert_eq!(hopped, vec![4, 5, 9, 10, 14, 15, 19]);
+    }
+
+    #[test]
+    fn should_take_then_skip_with_new() {
+        let hopped: Vec<_> = Hopper::new(1..20, 2, 3, true).collect();
+        assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+    }
+
+    #[test]
+    fn should_return_error_for_zero_durations() {
+        assert_eq!(
+            (1..20).try_hopp(0, 3).err(),
+            Some(HopperError::ZeroDuration)
+        );
+        assert_eq!(
+            (1..20).try_hopp(2, 0).err(),
+            Some(HopperError::ZeroDuration)
+        );
+        assert_eq!(
+            Hopper::try_new(1..20, 0, 0, false).err(),
+            Some(HopperError::ZeroDuration)
+        );
+
+        let hopped: Vec<_> = (1..20).try_hopp(2, 3).unwrap().collect();
+        assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+    }
+
+    #[test]
+    fn should_return_same_with_nth_as_with_next() {
+        for (take, skip, start) in [(2, 3, true), (3, 2, false), (1, 5, true), (4, 1, false)] {
+            for n in 0..30 {
+                let mut hopper = Hopper::new(1..40, take, skip, start);
+                let mut expected = Hopper::new(1..40, take, skip, start);
+                for _ in 0..n {
+                    expected.next();
+                }
+
+                assert_eq!(hopper.nth(n), expected.next(), "nth({n}) of {take} {skip}");
+                assert_eq!(hopper.next(), expected.next(), "next after nth({n})");
+            }
+        }
+
+        let mut hopper = (1..1000).hopp_times(2, 3, 3);
+        assert_eq!(hopper.nth(4), Some(11));
+        assert_eq!(hopper.nth(1), None);
+    }
+
+    #[test]
+    fn should_collect_hopped() {
+        let hopped: Vec<_> = (1..20).hopp_collect(2, 3);
+        assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+
+        let hopped: std::collections::HashSet<_> = [1, 2, 3, 2, 1].into_iter().hopp_collect(2, 1);
+        assert_eq!(hopped, [1, 2].into_iter().collect());
+    }
+
+    #[test]
+    fn should_sample_n_of_every_m() {
+        let sampled: Vec<_> = (1..=10).sample_ratio(1, 3).unwrap().collect();
+        assert_eq!(sampled, vec![1, 4, 7, 10]);
+
+        let sampled: Vec<_> = (1..=5).sample_ratio(2, 2).unwrap().collect();
+        assert_eq!(sampled, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn should_return_error_for_invalid_ratio() {
+        assert_eq!(
+            (1..=10).sample_ratio(4, 3).err(),
+            Some(HopperError::TakeExceedsWindow { take: 4, window: 3 })
+        );
+        assert_eq!(
+            (1..=10).sample_ratio(0, 3).err(),
+            Some(HopperError::ZeroDuration)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_for_zero_duration_with_new() {
+        let _ = (1..20).hopp(0, 3);
+    }
+
+    #[test]
+    fn should_peek_what_next_returns() {
+        let mut hopper = (1..20).peekable().hopp(2, 3);
+        let mut peeked_and_taken = Vec::new();
+        while let Some(&peeked) = hopper.peek() {
+            assert_eq!(hopper.peek(), Some(&peeked));
+            assert_eq!(hopper.next(), Some(peeked));
+            peeked_and_taken.push(peeked);
+        }
+
+        assert_eq!(peeked_and_taken, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+        assert_eq!(hopper.next(), None);
+
+        let mut hopper = (1..1000).peekable().hopp_times(2, 3, 1);
+        hopper.next();
+        hopper.next();
+        assert_eq!(hopper.peek(), None);
+    }
+
+    #[test]
+    fn should_know_remaining_in_window() {
+        let mut hopper = (1..20).hopp(2, 3);
+        assert_eq!(hopper.remaining_in_window(), 2);
+        hopper.next();
+        assert_eq!(hopper.remaining_in_window(), 1);
+        hopper.next();
+        assert_eq!(hopper.remaining_in_window(), 3);
+
+        let mut hopper = (1..20).hopp_past_start(2, 3);
+        assert_eq!(hopper.remaining_in_window(), 3);
+        hopper.next();
+        assert_eq!(hopper.remaining_in_window(), 1);
+
+        let mut hopper = (1..20).hopp_times(2, 3, 1);
+        hopper.next();
+        hopper.next();
+        assert_eq!(hopper.remaining_in_window(), usize::MAX);
+    }
+
+    #[test]
+    fn should_stop_after_cycles() {
+        let hopped: Vec<_> = (1..1000).hopp_times(2, 3, 2).collect();
+        assert_eq!(hopped, vec![1, 2, 6, 7]);
+
+        let mut hopper = (1..1000).hopp_times(2, 3, 2);
+        assert_eq!(hopper.len(), 4);
+        assert_eq!(hopper.next_back(), Some(7));
+        hopper.next();
+        assert_eq!(hopper.len(), 2);
+        assert_eq!(hopper.collect::<Vec<_>>(), vec![2, 6]);
+
+        let hopped: Vec<_> = (1..1000).hopp_past_start(2, 3).with_cycles(1).collect();
+        assert_eq!(hopped, vec![4, 5]);
+        assert_eq!((1..10).hopp_times(2, 3, 5).len(), 4);
+    }
+
+    #[test]
+    fn should_alternate_phase() {
+        let mut hopper = (1..20).hopp(2, 3);
+        let mut phases = Vec::new();
+        for _ in 0..5 {
+            phases.push(hopper.phase());
+            hopper.next();
+        }
+
+        use HopPhase::*;
+        assert_eq!(phases, vec![Inside, Inside, Outside, Inside, Outside]);
+        assert_eq!((1..20).hopp_past_start(2, 3).phase(), Outside);
+        assert_eq!(Hopper::new_lenient(1..20, 1, 0, true).phase(), Inside);
+        assert_eq!(Hopper::new_lenient(1..20, 0, 1, true).phase(), Outside);
+    }
+
+    #[test]
+    fn should_take_everything_with_lenient_zero_skip() {
+        let hopped: Vec<_> = Hopper::new_lenient(1..10, 2, 0, true).collect();
+        assert_eq!(hopped, (1..10).collect::<Vec<_>>());
+
+        let hopper = Hopper::new_lenient(1..10, 3, 0, false);
+        assert_eq!(hopper.len(), 9);
+        assert_eq!(
+            hopper.rev().collect::<Vec<_>>(),
+            (1..10).rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_take_nothing_with_lenient_zero_take() {
+        let mut hopper = Hopper::new_lenient(1..10, 0, 2, true);
+        assert_eq!(hopper.len(), 0);
+        assert_eq!(hopper.next(), None);
+        assert_eq!(hopper.next_back(), None);
+
+        assert_eq!(Hopper::new_lenient(1..10, 0, 0, false).count(), 0);
+    }
+
+    #[test]
+    fn should_handle_durations_beyond_u32() {
+        let big = u32::MAX as usize + 5;
+
+        let taken: Vec<_> = (0..).hopp(big, 2).take(3).collect();
+        assert_eq!(taken, vec![0, 1, 2]);
+        assert_eq!((0..big + 10).hopp(big, 3).len(), big + 7);
+
+        let mut past_start = (0..big + 5).hopp_past_start(2, big);
+        assert_eq!(past_start.len(), 2);
+        assert_eq!(past_start.next_back(), Some(big + 1));
+        assert_eq!(past_start.next_back(), Some(big));
+        assert_eq!(past_start.len(), 0);
+    }
+
+    #[test]
+    fn should_restart_pattern_after_reset() {
+        let mut hopper = (1..20).hopp(2, 3);
+        assert_eq!(hopper.next(), Some(1));
+        hopper.reset();
+
+        let rest: Vec<_> = hopper.collect();
+        assert_eq!(rest, vec![2, 3, 7, 8, 12, 13, 17, 18]);
+
+        let mut hopper = (1..20).hopp_past_start(2, 3);
+        assert_eq!(hopper.next(), Some(4));
+        hopper.reset();
+
+        let rest: Vec<_> = hopper.collect();
+        assert_eq!(rest, (5..20).hopp_past_start(2, 3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_continue_clone_like_original() {
+        let mut hopper = (1..40).hopp(2, 3);
+        hopper.next();
+        hopper.next();
+        hopper.next();
+
+        let cloned = hopper.clone();
+
+        assert_eq!(hopper.collect::<Vec<_>>(), cloned.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_know_len_as_collected_count() {
+        for end in 1..40 {
+            let hopper = (1..end).hopp(2, 3);
+            let len = hopper.len();
+
+            assert_eq!(len, hopper.count(), "Wrong len for range up to {end}");
+        }
+        assert_eq!((1..40).hopp(2, 3).len(), (1..40).hopp(2, 3).count());
+    }
+
+    #[test]
+    fn should_know_len_while_consumed() {
+        let mut hopper = (1..40).hopp_past_start(4, 7);
+        while hopper.len() > 0 {
+            let len_before = hopper.len();
+            assert!(hopper.next().is_some());
+            assert_eq!(hopper.len(), len_before - 1);
+        }
+
+        assert_eq!(hopper.next(), None);
+    }
+
+    #[test]
+    fn should_return_from_back_what_front_returns() {
+        let from_back: Vec<_> = (1..20).hopp(2, 3).rev().collect();
+        assert_eq!(from_back, vec![17, 16, 12, 11, 7, 6, 2, 1]);
+
+        for end in 1..40 {
+            let mut from_front: Vec<_> = (1..end).hopp_past_start(3, 2).collect();
+            from_front.reverse();
+            let from_back: Vec<_> = (1..end).hopp_past_start(3, 2).rev().collect();
+
+            assert_eq!(from_front, from_back, "Not aligned for range up to {end}");
+        }
+    }
+
+    #[test]
+    fn should_meet_in_middle_from_both_ends() {
+        let mut hopper = (1..20).hopp(2, 3);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+
+        loop {
+            match (hopper.next(), hopper.next_back()) {
+                (None, None) => break,
+                (next, next_back) => {
+                    front.extend(next);
+                    back.extend(next_back);
+                }
+            }
+        }
+        back.reverse();
+        front.extend(back);
+
+        assert_eq!(front, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+        assert_eq!(hopper.next(), None);
+        assert_eq!(hopper.next_back(), None);
+    }
+
+    #[test]
+    fn should_hint_size_of_unknown_inner() {
+        let hopper = (1..20).filter(|x| x % 2 == 0).hopp(2, 3);
+
+        assert_eq!(hopper.size_hint(), (0, Some(8)));
+    }
+}
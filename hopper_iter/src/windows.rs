@@ -0,0 +1,59 @@
+use crate::{HopPhase, Hopper};
+
+/// Groups the returned elements of a [`Hopper`] into one vector per take window.
+/// The last window can be shorter than the others.
+///
+/// Created by [`crate::HopperExt::hopp_windows`].
+#[derive(Clone, Debug)]
+pub struct HopperWindows<I> {
+    hopper: Hopper<I>,
+}
+
+impl<I> HopperWindows<I> {
+    pub fn new(hopper: Hopper<I>) -> Self {
+        HopperWindows { hopper }
+    }
+}
+
+impl<I> Iterator for HopperWindows<I>
+where
+    I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let in_window = match self.hopper.phase() {
+            HopPhase::Inside => self.hopper.remaining_in_window(),
+            HopPhase::Outside => self.hopper.take,
+        };
+        let window: Vec<_> = self.hopper.by_ref().take(in_window).collect();
+
+        if window.is_empty() {
+            None
+        } else {
+            Some(window)
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::HopperExt;
+
+    #[test]
+    fn should_group_take_windows() {
+        let windows: Vec<_> = (1..20).hopp_windows(2, 3).collect();
+
+        assert_eq!(
+            windows,
+            vec![vec![1, 2], vec![6, 7], vec![11, 12], vec![16, 17]]
+        );
+    }
+
+    #[test]
+    fn should_return_shorter_last_window() {
+        let windows: Vec<_> = (1..10).hopp_windows(3, 1).collect();
+
+        assert_eq!(windows, vec![vec![1, 2, 3], vec![5, 6, 7], vec![9]]);
+    }
+}
@@ -0,0 +1,18 @@
+use hopper_iter::{Hopper, HopperExt};
+
+#[test]
+fn should_hopp_iterators_of_other_crates() {
+    let hopped: Vec<_> = (1..20).hopp(2, 3).collect();
+    assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+
+    let words = ["a", "b", "c", "d", "e"];
+    let hopped: Vec<_> = words.iter().copied().hopp_past_start(1, 1).collect();
+    assert_eq!(hopped, vec!["b", "d"]);
+}
+
+#[test]
+fn should_name_hopper_type_outside_crate() {
+    let hopper: Hopper<std::ops::Range<u32>> = (1..20).hopp(2, 3);
+
+    assert_eq!(hopper.len(), 8);
+}
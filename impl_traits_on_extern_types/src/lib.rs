@@ -0,0 +1,276 @@
+//! Shows how to implement an own trait on types from the std library.
+//! ```
+//! use impl_traits_on_extern_types::HasDataWithLength;
+//!
+//! assert_eq!("hi".get_data_with_length(), (2, String::from("hi")));
+//! ```
+use std::{collections::HashMap, fmt::Display};
+
+// Lets the derived implementations name this crate from within its own tests too.
+extern crate self as impl_traits_on_extern_types;
+
+/// Implements [`HasDataWithLength`] for a struct with one field annotated with `#[length]`
+/// and one with `#[data]`.
+/// ```
+/// use impl_traits_on_extern_types::HasDataWithLength;
+///
+/// #[derive(HasDataWithLength)]
+/// struct Message {
+///     #[length]
+///     size: usize,
+///     #[data]
+///     text: String,
+/// }
+///
+/// let message = Message { size: 5, text: String::from("Hello") };
+///
+/// assert_eq!(message.get_data_with_length(), (5, String::from("Hello")));
+/// ```
+pub use has_data_with_length_derive::HasDataWithLength;
+
+/// A length together with data, which can be displayed.
+///
+/// Every type with Display has this trait with the number of displayed characters as length.
+/// Because of this, Vec, slices, HashMap and Option can not have own implementations, since
+/// the std library could implement Display for them later. They get the trait through the
+/// wrappers [`Joined`], [`Entries`] and [`OrEmpty`] instead.
+pub trait HasDataWithLength {
+    type Data: Display;
+    fn get_data_with_length(&self) -> (usize, Self::Data);
+}
+
+// String, str or numbers are types from the std library.
+// We can impl our own traits on them however.
+// This is nice for mocking for example.
+// With a blanket implementation every type, which implements Display, gets this trait, even
+// types from other crates or ones written later on.
+// The length is the number of characters in the displayed text.
+impl<T> HasDataWithLength for T
+where
+    T: Display + ?Sized,
+{
+    type Data = String;
+    fn get_data_with_length(&self) -> (usize, Self::Data) {
+        let data = self.to_string();
+        (data.chars().count(), data)
+    }
+}
+
+// Own wrapper types never implement Display, so they do not overlap with the blanket
+// implementation.
+
+/// Wraps a slice or a Vec, the length is the number of elements.
+/// The data are the displayed elements separated by ", ".
+/// ```
+/// use impl_traits_on_extern_types::{HasDataWithLength, Joined};
+///
+/// let numbers = vec![1, 2, 3];
+///
+/// assert_eq!(Joined(&numbers).get_data_with_length(), (3, String::from("1, 2, 3")));
+/// ```
+pub struct Joined<'a, T>(pub &'a [T]);
+
+impl<T> HasDataWithLength for Joined<'_, T>
+where
+    T: Display,
+{
+    type Data = String;
+    fn get_data_with_length(&self) -> (usize, Self::Data) {
+        let data = self
+            .0
+            .iter()
+            .map(|element| element.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        (self.0.len(), data)
+    }
+}
+
+/// Wraps a HashMap, the length is the number of entries.
+/// The data are the entries as "key=value" separated by ", ".
+/// The entries are sorted by their text since a HashMap has no order.
+/// ```
+/// use std::collections::HashMap;
+/// use impl_traits_on_extern_types::{Entries, HasDataWithLength};
+///
+/// let stock = HashMap::from([("pears", 3), ("apples", 5)]);
+///
+/// assert_eq!(
+///     Entries(&stock).get_data_with_length(),
+///     (2, String::from("apples=5, pears=3"))
+/// );
+/// ```
+pub struct Entries<'a, K, V, S>(pub &'a HashMap<K, V, S>);
+
+impl<K, V, S> HasDataWithLength for Entries<'_, K, V, S>
+where
+    K: Display,
+    V: Display,
+{
+    type Data = String;
+    fn get_data_with_length(&self) -> (usize, Self::Data) {
+        let mut entries: Vec<_> = self
+            .0
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+        entries.sort();
+        (self.0.len(), entries.join(", "))
+    }
+}
+
+/// Wraps an Option and uses the implementation of the inner value.
+/// None has a length of 0 and an empty text as data.
+/// ```
+/// use impl_traits_on_extern_types::{HasDataWithLength, OrEmpty};
+///
+/// assert_eq!(OrEmpty(&Some("hi")).get_data_with_length(), (2, String::from("hi")));
+/// assert_eq!(OrEmpty(&None::<String>).get_data_with_length(), (0, String::new()));
+/// ```
+pub struct OrEmpty<'a, T>(pub &'a Option<T>);
+
+impl<T> HasDataWithLength for OrEmpty<'_, T>
+where
+    T: HasDataWithLength,
+{
+    type Data = String;
+    fn get_data_with_length(&self) -> (usize, Self::Data) {
+        match self.0 {
+            Some(inner) => {
+                let (length, data) = inner.get_data_with_length();
+                (length, data.to_string())
+            }
+            None => (0, String::new()),
+        }
+    }
+}
+
+pub fn print_count_with_data<T>(to_print: &T)
+where
+    T: HasDataWithLength + ?Sized,
+{
+    print!("{}", format_count_with_data(to_print));
+}
+
+/// Returns the lines, which [`print_count_with_data`] prints.
+pub fn format_count_with_data<T>(to_format: &T) -> String
+where
+    T: HasDataWithLength + ?Sized,
+{
+    let data = to_format.get_data_with_length();
+    format!("Count: {}\nData: {}\n", data.0, data.1)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    struct Celsius(i32);
+
+    impl Display for Celsius {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}°C", self.0)
+        }
+    }
+
+    #[test]
+    fn should_count_displayed_number() {
+        assert_eq!(42u32.get_data_with_length(), (2, String::from("42")));
+        assert_eq!((-1.5f64).get_data_with_length(), (4, String::from("-1.5")));
+    }
+
+    #[test]
+    fn should_count_char() {
+        assert_eq!('ä'.get_data_with_length(), (1, String::from("ä")));
+    }
+
+    #[test]
+    fn should_count_chars_of_custom_display() {
+        assert_eq!(
+            Celsius(-20).get_data_with_length(),
+            (5, String::from("-20°C"))
+        );
+    }
+
+    #[test]
+    fn should_count_chars_of_strings() {
+        assert_eq!(
+            String::from("Grüße").get_data_with_length(),
+            (5, String::from("Grüße"))
+        );
+        assert_eq!("Hello".get_data_with_length(), (5, String::from("Hello")));
+    }
+
+    #[test]
+    fn should_count_elements_of_vec() {
+        let numbers: Vec<u32> = (1..=3).collect();
+
+        assert_eq!(
+            Joined(&numbers).get_data_with_length(),
+            (3, String::from("1, 2, 3"))
+        );
+        assert_eq!(
+            Joined(&Vec::<u8>::new()).get_data_with_length(),
+            (0, String::new())
+        );
+    }
+
+    #[test]
+    fn should_count_entries_of_map() {
+        let map = HashMap::from([("b", 2), ("a", 1), ("c", 3)]);
+        let (length, data) = Entries(&map).get_data_with_length();
+
+        assert_eq!(length, 3);
+        for pair in ["a=1", "b=2", "c=3"] {
+            assert!(data.contains(pair), "{pair} is missing in {data}");
+        }
+        assert_eq!(data, "a=1, b=2, c=3");
+    }
+
+    #[test]
+    fn should_count_chars_of_boxed_display() {
+        let boxed: Vec<Box<dyn Display>> = vec![Box::new(1234u32), Box::new(String::from("hi"))];
+        let data: Vec<_> = boxed.iter().map(|b| b.get_data_with_length()).collect();
+
+        assert_eq!(
+            data,
+            vec![(4, String::from("1234")), (2, String::from("hi"))]
+        );
+    }
+
+    #[test]
+    fn should_format_count_with_data() {
+        assert_eq!(format_count_with_data("Hello"), "Count: 5\nData: Hello\n");
+    }
+
+    #[test]
+    fn should_use_inner_of_some() {
+        assert_eq!(
+            OrEmpty(&Some("hi")).get_data_with_length(),
+            (2, String::from("hi"))
+        );
+        assert_eq!(
+            OrEmpty(&Some(Joined(&[1, 2]))).get_data_with_length(),
+            (2, String::from("1, 2"))
+        );
+    }
+
+    #[test]
+    fn should_have_nothing_for_none() {
+        assert_eq!(
+            OrEmpty(&None::<String>).get_data_with_length(),
+            (0, String::new())
+        );
+    }
+
+    #[test]
+    fn should_count_elements_of_slice() {
+        let words = ["a", "bc"];
+        let slice: &[&str] = &words;
+
+        assert_eq!(
+            Joined(slice).get_data_with_length(),
+            (2, String::from("a, bc"))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use impl_traits_on_extern_types::{format_count_with_data, HasDataWithLength};
+
+#[derive(HasDataWithLength)]
+struct Packet {
+    id: u8,
+    #[data]
+    payload: String,
+    #[length]
+    payload_size: usize,
+}
+
+#[test]
+fn should_use_annotated_fields() {
+    let packet = Packet {
+        id: 1,
+        payload: String::from("42"),
+        payload_size: 2,
+    };
+
+    assert_eq!(packet.id, 1);
+    assert_eq!(packet.get_data_with_length(), (2, String::from("42")));
+    assert_eq!(format_count_with_data(&packet), "Count: 2\nData: 42\n");
+}
+
+#[test]
+fn should_compile_only_with_annotated_fields() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/annotated.rs");
+    cases.compile_fail("tests/ui/missing_length.rs");
+    cases.compile_fail("tests/ui/signed_length.rs");
+}
@@ -0,0 +1,17 @@
+use impl_traits_on_extern_types::HasDataWithLength;
+
+#[derive(HasDataWithLength)]
+struct Word<'a> {
+    #[length]
+    letters: u16,
+    #[data]
+    text: &'a str,
+}
+
+fn main() {
+    let word = Word {
+        letters: 2,
+        text: "hi",
+    };
+    assert_eq!(word.get_data_with_length(), (2, String::from("hi")));
+}
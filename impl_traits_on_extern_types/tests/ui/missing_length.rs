@@ -0,0 +1,9 @@
+use impl_traits_on_extern_types::HasDataWithLength;
+
+#[derive(HasDataWithLength)]
+struct Word {
+    #[data]
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use impl_traits_on_extern_types::HasDataWithLength;
+
+#[derive(HasDataWithLength)]
+struct Offset {
+    #[length]
+    delta: i32,
+    #[data]
+    label: String,
+}
+
+fn main() {}
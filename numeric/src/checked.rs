@@ -0,0 +1,103 @@
+//! Sums of user supplied numbers without overflow. Every function returns None instead of
+//! overflowing, so parsers can turn it into an error and constructors into a clear panic.
+
+const SECS_PER_MIN: u64 = 60;
+const MINS_PER_HOUR: u64 = 60;
+const HOURS_PER_DAY: u64 = 24;
+const DAYS_PER_WEEK: u64 = 7;
+
+/// Returns `value * factor + addend` or None if it does not fit into u64.
+/// ```
+/// use numeric::checked::checked_mul_add;
+///
+/// assert_eq!(checked_mul_add(3, 60, 5), Some(185));
+/// assert_eq!(checked_mul_add(u64::MAX, 2, 0), None);
+/// ```
+pub fn checked_mul_add(value: u64, factor: u64, addend: u64) -> Option<u64> {
+    value.checked_mul(factor)?.checked_add(addend)
+}
+
+/// All seconds of the hours, minutes and seconds together.
+/// ```
+/// use numeric::checked::checked_total_seconds;
+///
+/// assert_eq!(checked_total_seconds(1, 2, 3), Some(3723));
+/// assert_eq!(checked_total_seconds(u64::MAX, 0, 0), None);
+/// ```
+pub fn checked_total_seconds(hours: u64, mins: u64, secs: u64) -> Option<u64> {
+    let total_mins = checked_mul_add(hours, MINS_PER_HOUR, mins)?;
+    checked_mul_add(total_mins, SECS_PER_MIN, secs)
+}
+
+/// Same as [`checked_total_seconds`] with days before the hours.
+pub fn checked_total_seconds_with_days(days: u64, hours: u64, mins: u64, secs: u64) -> Option<u64> {
+    checked_total_seconds(checked_mul_add(days, HOURS_PER_DAY, hours)?, mins, secs)
+}
+
+/// Same as [`checked_total_seconds_with_days`] with weeks before the days.
+pub fn checked_total_seconds_with_weeks(
+    weeks: u64,
+    days: u64,
+    hours: u64,
+    mins: u64,
+    secs: u64,
+) -> Option<u64> {
+    checked_total_seconds_with_days(
+        checked_mul_add(weeks, DAYS_PER_WEEK, days)?,
+        hours,
+        mins,
+        secs,
+    )
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_return_largest_total_seconds() {
+        assert_eq!(checked_total_seconds(0, 0, u64::MAX), Some(u64::MAX));
+        assert_eq!(
+            checked_total_seconds(u64::MAX / 3600, 0, u64::MAX % 3600),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            checked_total_seconds(0, u64::MAX / 60, u64::MAX % 60),
+            Some(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn should_return_none_one_above_largest_total_seconds() {
+        assert_eq!(checked_total_seconds(0, 1, u64::MAX - 59), None);
+        assert_eq!(
+            checked_total_seconds(u64::MAX / 3600, 0, u64::MAX % 3600 + 1),
+            None
+        );
+        assert_eq!(checked_total_seconds(0, u64::MAX / 60 + 1, 0), None);
+    }
+
+    #[test]
+    fn should_return_none_if_only_intermediate_overflows() {
+        // Minutes alone fit, but not after the hours are added to them.
+        assert_eq!(checked_total_seconds(u64::MAX / 60, u64::MAX, 0), None);
+    }
+
+    #[test]
+    fn should_add_days_and_weeks() {
+        assert_eq!(checked_total_seconds_with_days(1, 1, 1, 1), Some(90_061));
+        assert_eq!(
+            checked_total_seconds_with_weeks(1, 0, 0, 0, 0),
+            Some(604_800)
+        );
+        assert_eq!(
+            checked_total_seconds_with_days(u64::MAX / 24 + 1, 0, 0, 0),
+            None
+        );
+        assert_eq!(
+            checked_total_seconds_with_weeks(u64::MAX / 7 + 1, 0, 0, 0, 0),
+            None
+        );
+        assert_eq!(checked_total_seconds_with_weeks(0, 0, 0, 0, 0), Some(0));
+    }
+}
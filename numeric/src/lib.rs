@@ -0,0 +1,72 @@
+//! Integer types shared by the crates of this workspace, so their callers can pick the width.
+//! ```
+//! use numeric::Numeric;
+//!
+//! fn double_or_none<N: Numeric>(value: N) -> Option<N> {
+//!     value.checked_add(value)
+//! }
+//!
+//! assert_eq!(double_or_none(20u8), Some(40));
+//! assert_eq!(double_or_none(200u8), None);
+//! ```
+#![no_std]
+
+use core::ops::{Add, Sub};
+
+pub mod checked;
+
+/// Operations on unsigned integers, which are needed for amounts and times.
+pub trait Numeric: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// None instead of an overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// None instead of going below zero.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_numeric {
+    ($($unsigned:ty),* $(,)?) => {
+        $(
+            impl Numeric for $unsigned {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$unsigned>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$unsigned>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn sum_all<N: Numeric>(values: &[N]) -> Option<N> {
+        values
+            .iter()
+            .try_fold(N::ZERO, |sum, &value| sum.checked_add(value))
+    }
+
+    #[test]
+    fn should_return_none_on_overflow() {
+        assert_eq!(sum_all(&[100u8, 100, 55]), Some(255));
+        assert_eq!(sum_all(&[100u8, 100, 56]), None);
+        assert_eq!(sum_all(&[u128::MAX, 0]), Some(u128::MAX));
+    }
+
+    #[test]
+    fn should_return_none_below_zero() {
+        assert_eq!(Numeric::checked_sub(5u32, 5), Some(u32::ZERO));
+        assert_eq!(Numeric::checked_sub(u64::ZERO, u64::ONE), None);
+    }
+}
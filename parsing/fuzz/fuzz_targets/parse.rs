@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use parsing::fuzzing::check_parse_never_panics;
+
+fuzz_target!(|data: &[u8]| check_parse_never_panics(data));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use parsing::fuzzing::{check_round_trip, time_from_parts};
+
+fuzz_target!(|parts: (u64, u16)| check_round_trip(time_from_parts(parts.0, parts.1)));
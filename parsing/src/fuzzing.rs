@@ -0,0 +1,27 @@
+//! Checks shared by the property tests and the `cargo fuzz` targets in `parsing/fuzz`.
+//! Both only generate the input and leave the asserting to these functions.
+
+use crate::TimeInTime;
+use std::str;
+
+/// Builds a time from raw generated parts. Milliseconds at or above 1000 are kept below it.
+pub fn time_from_parts(total_secs: u64, millis: u16) -> TimeInTime {
+    TimeInTime::new(total_secs).with_millis(millis % 1000)
+}
+
+/// Panics if the displayed time does not parse back into the same time.
+pub fn check_round_trip(time: TimeInTime) {
+    let text = time.to_string();
+    let parsed: TimeInTime = text
+        .parse()
+        .unwrap_or_else(|error| panic!("{text:?} of {time:?} does not parse back: {error}"));
+    assert_eq!(parsed, time, "{text:?} parses to another time");
+}
+
+/// Feeds arbitrary bytes to the parsers. Only a panic counts as a failure, errors are fine.
+pub fn check_parse_never_panics(data: &[u8]) {
+    if let Ok(text) = str::from_utf8(data) {
+        let _ = text.parse::<TimeInTime>();
+        let _ = crate::parse_units(text);
+    }
+}
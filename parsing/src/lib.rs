@@ -0,0 +1,1211 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Sub};
+use std::str;
+use std::time::Duration;
+
+use numeric::{checked, Numeric};
+
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+#[cfg(feature = "serde")]
+mod serde_support;
+
+// Ordering compares the total seconds first and then the milliseconds.
+// Default is a time of zero like `TimeInTime::ZERO`.
+// The seconds are u64 if not given. Parsing, displaying and the units above seconds need u64,
+// the arithmetic works with any width of `Numeric`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeInTime<S = u64> {
+    total_secs: S,
+    /// Milliseconds on top of the total seconds, always below 1000.
+    frac_millis: u16,
+}
+
+const MILLIS_PER_SEC: u16 = 1000;
+const MAX_FRACTION_DIGITS: usize = 3;
+
+impl<S: Numeric> TimeInTime<S> {
+    /// Same as `TimeInTime::new` but for seconds of any width.
+    /// ```
+    /// use parsing::TimeInTime;
+    ///
+    /// let time = TimeInTime::from_secs(90u32) + TimeInTime::from_secs(30u32);
+    ///
+    /// assert_eq!(time.as_secs(), 120u32);
+    /// ```
+    pub fn from_secs(total_secs: S) -> Self {
+        TimeInTime {
+            total_secs,
+            frac_millis: 0,
+        }
+    }
+
+    /// All whole seconds without the milliseconds.
+    pub fn as_secs(&self) -> S {
+        self.total_secs
+    }
+
+    /// None if the sum of the seconds does not fit into the type of the seconds.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let millis = self.frac_millis + rhs.frac_millis;
+        let carry = if millis >= MILLIS_PER_SEC {
+            S::ONE
+        } else {
+            S::ZERO
+        };
+
+        Some(TimeInTime {
+            total_secs: self
+                .total_secs
+                .checked_add(rhs.total_secs)?
+                .checked_add(carry)?,
+            frac_millis: millis % MILLIS_PER_SEC,
+        })
+    }
+
+    /// None if the right side is the longer time.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let total_secs = self.total_secs.checked_sub(rhs.total_secs)?;
+
+        if self.frac_millis >= rhs.frac_millis {
+            Some(TimeInTime {
+                total_secs,
+                frac_millis: self.frac_millis - rhs.frac_millis,
+            })
+        } else {
+            Some(TimeInTime {
+                total_secs: total_secs.checked_sub(S::ONE)?,
+                frac_millis: self.frac_millis + MILLIS_PER_SEC - rhs.frac_millis,
+            })
+        }
+    }
+}
+
+impl TimeInTime {
+    pub const ZERO: TimeInTime = TimeInTime {
+        total_secs: 0,
+        frac_millis: 0,
+    };
+
+    pub fn new(total_secs: u64) -> Self {
+        TimeInTime {
+            total_secs,
+            frac_millis: 0,
+        }
+    }
+    /// Panics if the total seconds do not fit into u64.
+    pub fn with_ms(mins: u64, secs: u64) -> Self {
+        TimeInTime::with_hms(0, mins, secs)
+    }
+
+    /// Panics if the total seconds do not fit into u64. See `checked_with_hms` to get None.
+    pub fn with_hms(hours: u64, mins: u64, secs: u64) -> Self {
+        TimeInTime::new(expect_fits(checked::checked_total_seconds(
+            hours, mins, secs,
+        )))
+    }
+
+    /// Returns None if the total seconds do not fit into u64.
+    pub fn checked_with_hms(hours: u64, mins: u64, secs: u64) -> Option<Self> {
+        checked::checked_total_seconds(hours, mins, secs).map(TimeInTime::new)
+    }
+
+    /// Clamps the total seconds to u64::MAX instead of overflowing.
+    pub fn saturating_with_hms(hours: u64, mins: u64, secs: u64) -> Self {
+        TimeInTime::new(
+            hours
+                .saturating_mul(60 * 60)
+                .saturating_add(mins.saturating_mul(60))
+                .saturating_add(secs),
+        )
+    }
+
+    /// Returns None if the total seconds do not fit into u64.
+    pub fn checked_with_dhms(days: u64, hours: u64, mins: u64, secs: u64) -> Option<Self> {
+        checked::checked_total_seconds_with_days(days, hours, mins, secs).map(TimeInTime::new)
+    }
+
+    /// Milliseconds of 1000 and above are carried over into the seconds.
+    /// Panics if the carried over seconds do not fit into u64.
+    pub fn with_millis(self, millis: u16) -> Self {
+        TimeInTime {
+            total_secs: expect_fits(
+                self.total_secs
+                    .checked_add(u64::from(millis / MILLIS_PER_SEC)),
+            ),
+            frac_millis: millis % MILLIS_PER_SEC,
+        }
+    }
+
+    /// Panics if the total seconds do not fit into u64.
+    pub fn with_dhms(days: u64, hours: u64, mins: u64, secs: u64) -> Self {
+        TimeInTime::new(expect_fits(checked::checked_total_seconds_with_days(
+            days, hours, mins, secs,
+        )))
+    }
+
+    /// Panics if the total seconds do not fit into u64.
+    pub fn with_wdhms(weeks: u64, days: u64, hours: u64, mins: u64, secs: u64) -> Self {
+        TimeInTime::new(expect_fits(checked::checked_total_seconds_with_weeks(
+            weeks, days, hours, mins, secs,
+        )))
+    }
+
+    /// Seconds of the last minute. See `as_secs` for all seconds.
+    pub fn get_secs(&self) -> u64 {
+        self.total_secs % 60
+    }
+
+    /// Minutes of the last hour. See `as_minutes` for all minutes.
+    pub fn get_minutes(&self) -> u64 {
+        (self.total_secs / 60) % 60
+    }
+
+    pub fn get_hours(&self) -> u64 {
+        self.total_secs / (60 * 60)
+    }
+
+    /// Returns how far apart both times are, regardless which one is the later one.
+    /// ```
+    /// use parsing::TimeInTime;
+    ///
+    /// let start = TimeInTime::with_hms(0, 45, 0);
+    /// let end = TimeInTime::with_hms(1, 0, 0);
+    ///
+    /// assert_eq!(TimeInTime::between(&end, &start), TimeInTime::with_hms(0, 15, 0));
+    /// ```
+    pub fn between(a: &TimeInTime, b: &TimeInTime) -> TimeInTime {
+        TimeInTime::from_total_millis(a.total_millis().abs_diff(b.total_millis()))
+    }
+
+    /// Returns the time in words like "1 hour, 2 minutes, 3 seconds" for messages to users.
+    /// Days of 7 and above are shown as weeks.
+    /// Components which are zero are left out and milliseconds are dropped.
+    /// ```
+    /// use parsing::TimeInTime;
+    ///
+    /// assert_eq!(TimeInTime::with_hms(1, 0, 3).humanize(), "1 hour, 3 seconds");
+    /// assert_eq!(TimeInTime::new(0).humanize(), "0 seconds");
+    /// ```
+    pub fn humanize(&self) -> String {
+        let (days, hours, mins, secs) = self.get_days_hours_mins_secs();
+        let words: Vec<String> = [
+            (self.get_weeks(), "week"),
+            (days % 7, "day"),
+            (hours, "hour"),
+            (mins, "minute"),
+            (secs, "second"),
+        ]
+        .iter()
+        .filter(|(count, _)| *count != 0)
+        .map(|(count, unit)| {
+            let plural = if *count == 1 { "" } else { "s" };
+            format!("{count} {unit}{plural}")
+        })
+        .collect();
+
+        if words.is_empty() {
+            "0 seconds".to_string()
+        } else {
+            words.join(", ")
+        }
+    }
+
+    /// Formats the time as a clock time in the 12-hour form like "01:30:00 PM".
+    /// Days are dropped so only the time within the last day is shown.
+    /// ```
+    /// use parsing::TimeInTime;
+    ///
+    /// assert_eq!(TimeInTime::with_hms(0, 0, 0).format_12h(), "12:00:00 AM");
+    /// assert_eq!(TimeInTime::with_hms(13, 30, 0).format_12h(), "01:30:00 PM");
+    /// ```
+    pub fn format_12h(&self) -> String {
+        let (_, hours, mins, secs) = self.get_days_hours_mins_secs();
+        let suffix = if hours < 12 { "AM" } else { "PM" };
+        let hours_12 = match hours % 12 {
+            0 => 12,
+            hours => hours,
+        };
+
+        format!("{hours_12:02}:{mins:02}:{secs:02} {suffix}")
+    }
+
+    /// Returns how much of a day the time covers in percent, for example 50.0 for noon.
+    /// Times longer than a day are not clamped and return more than 100.
+    pub fn percent_of_day(&self) -> f64 {
+        const MILLIS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+        self.total_millis() as f64 / MILLIS_PER_DAY * 100.0
+    }
+
+    /// All whole minutes, the seconds of the last minute are dropped.
+    pub fn as_minutes(&self) -> u64 {
+        self.total_secs / 60
+    }
+
+    /// All whole hours, the minutes of the last hour are dropped.
+    pub fn as_hours(&self) -> u64 {
+        self.total_secs / (60 * 60)
+    }
+
+    fn total_millis(&self) -> u128 {
+        u128::from(self.total_secs) * u128::from(MILLIS_PER_SEC) + u128::from(self.frac_millis)
+    }
+
+    fn from_total_millis(total_millis: u128) -> Self {
+        let millis_per_sec = u128::from(MILLIS_PER_SEC);
+        TimeInTime {
+            total_secs: u64::try_from(total_millis / millis_per_sec)
+                .expect("Total seconds of a time must fit into u64"),
+            frac_millis: (total_millis % millis_per_sec) as u16,
+        }
+    }
+
+    pub fn get_millis(&self) -> u16 {
+        self.frac_millis
+    }
+
+    pub fn get_days(&self) -> u64 {
+        self.total_secs / (60 * 60 * 24)
+    }
+
+    pub fn get_weeks(&self) -> u64 {
+        self.get_days() / 7
+    }
+
+    pub fn get_secs_minutes_hours(&self) -> (u64, u64, u64) {
+        (self.get_hours(), self.get_minutes(), self.get_secs())
+    }
+
+    /// Unlike `get_secs_minutes_hours` the hours are only the hours of the last day.
+    pub fn get_days_hours_mins_secs(&self) -> (u64, u64, u64, u64) {
+        (
+            self.get_days(),
+            self.get_hours() % 24,
+            self.get_minutes(),
+            self.get_secs(),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TimeParseError {
+    /// Segment at the index which is not a positive number.
+    /// Segments are separated by colons or units. Milliseconds are part of the last segment.
+    InvalidNumber { segment: String, index: usize },
+    /// More than the 4 segments of the form D:HH:MM:SS.
+    TooManySegments,
+    /// Hours of 24 and above in the form D:HH:MM:SS or
+    /// minutes and seconds of 60 and above in the forms MM:SS, HH:MM:SS and D:HH:MM:SS.
+    ComponentOutOfRange {
+        component: TimeComponent,
+        value: u64,
+    },
+    /// The total seconds do not fit into u64.
+    Overflow,
+    /// Letter after a number which is none of w, d, h, m or s.
+    UnknownUnit(char),
+    /// Same unit more than once like in "1m2m".
+    DuplicateUnit(char),
+    /// Number at the end without a unit like in "1h30".
+    MissingUnit,
+    /// Nothing or only whitespace to parse.
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TimeComponent {
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl fmt::Display for TimeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeParseError::InvalidNumber { segment, index } => {
+                write!(
+                    f,
+                    "segment {segment:?} at index {index} is not a positive number"
+                )
+            }
+            TimeParseError::TooManySegments => {
+                write!(
+                    f,
+                    "time has more segments than days, hours, minutes and seconds"
+                )
+            }
+            TimeParseError::ComponentOutOfRange { component, value } => write!(
+                f,
+                "{component} must be below {} but is {value}",
+                component.limit()
+            ),
+            TimeParseError::Overflow => write!(f, "time is too large to be stored in seconds"),
+            TimeParseError::UnknownUnit(unit) => {
+                write!(f, "unit {unit:?} is none of w, d, h, m or s")
+            }
+            TimeParseError::DuplicateUnit(unit) => write!(f, "unit {unit:?} appears twice"),
+            TimeParseError::MissingUnit => write!(f, "number at the end has no unit"),
+            TimeParseError::Empty => write!(f, "time is empty"),
+        }
+    }
+}
+
+impl std::error::Error for TimeParseError {}
+
+impl fmt::Display for TimeComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TimeComponent::Hours => "hours",
+            TimeComponent::Minutes => "minutes",
+            TimeComponent::Seconds => "seconds",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl TimeComponent {
+    /// Values of this component must be below this limit if a larger unit comes before it.
+    fn limit(self) -> u64 {
+        match self {
+            TimeComponent::Hours => 24,
+            TimeComponent::Minutes | TimeComponent::Seconds => 60,
+        }
+    }
+}
+
+const DAY_HOUR_MIN_SEC_INPUT: usize = 4;
+const HOUR_MIN_SEC_INPUT: usize = 3;
+const MIN_SEC_INPUT: usize = 2;
+const SEC_INPUT: usize = 1;
+
+/// Parses numbers with a unit after each like "1h30m15s", "90s" or "2w3d".
+/// The units w, d, h, m and s can appear in any order but only once.
+/// ```
+/// use parsing::{parse_units, TimeInTime};
+///
+/// assert_eq!(parse_units("15s1h30m"), Ok(TimeInTime::with_hms(1, 30, 15)));
+/// ```
+pub fn parse_units(s: &str) -> Result<TimeInTime, TimeParseError> {
+    if s.is_empty() {
+        return Err(TimeParseError::Empty);
+    }
+
+    let mut seen_units = Vec::new();
+    let mut total_secs: u64 = 0;
+    let mut number_start = 0;
+
+    for (unit_start, unit) in s.char_indices().filter(|(_, c)| !c.is_ascii_digit()) {
+        let secs_per_unit: u64 = match unit {
+            'w' => 60 * 60 * 24 * 7,
+            'd' => 60 * 60 * 24,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(TimeParseError::UnknownUnit(unit)),
+        };
+        if seen_units.contains(&unit) {
+            return Err(TimeParseError::DuplicateUnit(unit));
+        }
+        seen_units.push(unit);
+
+        let number: u64 = parse_number(&s[number_start..unit_start], seen_units.len() - 1)?;
+        total_secs = checked::checked_mul_add(number, secs_per_unit, total_secs)
+            .ok_or(TimeParseError::Overflow)?;
+        number_start = unit_start + unit.len_utf8();
+    }
+
+    if seen_units.is_empty() || number_start != s.len() {
+        return Err(TimeParseError::MissingUnit);
+    }
+
+    Ok(TimeInTime::new(total_secs))
+}
+
+impl str::FromStr for TimeInTime {
+    type Err = TimeParseError;
+    /// Accepts the forms D:HH:MM:SS, HH:MM:SS, MM:SS and SS with optional milliseconds like
+    /// "01:02:03.500" or numbers with units like "1h30m" via `parse_units`.
+    /// A text with a colon is always parsed as one of the forms with colons.
+    /// Whitespace around the whole time is ignored but not within it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(TimeParseError::Empty);
+        }
+        if !s.contains(':') && s.chars().any(char::is_alphabetic) {
+            return parse_units(s);
+        }
+
+        let (whole_secs, fraction) = match s.split_once('.') {
+            Some((whole_secs, fraction)) => (whole_secs, Some(fraction)),
+            None => (s, None),
+        };
+        let separator: Vec<&str> = whole_secs.split(':').collect();
+        let last_index = separator.len() - 1;
+        let millis = fraction
+            .map(|fraction| parse_millis(fraction, last_index))
+            .transpose()?
+            .unwrap_or(0);
+
+        let time = match separator.len() {
+            len if len == DAY_HOUR_MIN_SEC_INPUT => {
+                let days: u64 = parse_number(separator[0], 0)?;
+                let hours = parse_below_limit(&separator, 1, TimeComponent::Hours)?;
+                let mins = parse_below_limit(&separator, 2, TimeComponent::Minutes)?;
+                let secs = parse_below_limit(&separator, 3, TimeComponent::Seconds)?;
+
+                TimeInTime::checked_with_dhms(days, hours, mins, secs)
+                    .ok_or(TimeParseError::Overflow)
+            }
+            len if len == HOUR_MIN_SEC_INPUT => {
+                let hours: u64 = parse_number(separator[0], 0)?;
+                let mins = parse_below_limit(&separator, 1, TimeComponent::Minutes)?;
+                let secs = parse_below_limit(&separator, 2, TimeComponent::Seconds)?;
+
+                TimeInTime::checked_with_hms(hours, mins, secs).ok_or(TimeParseError::Overflow)
+            }
+            len if len == MIN_SEC_INPUT => {
+                let mins = parse_below_limit(&separator, 0, TimeComponent::Minutes)?;
+                let secs = parse_below_limit(&separator, 1, TimeComponent::Seconds)?;
+
+                Ok(TimeInTime::with_ms(mins, secs))
+            }
+            len if len == SEC_INPUT => {
+                let secs: u64 = parse_number(separator[0], 0)?;
+
+                Ok(TimeInTime::new(secs))
+            }
+            _ => Err(TimeParseError::TooManySegments),
+        }?;
+
+        return Ok(time.with_millis(millis));
+
+        /// Parses 1 up to 3 digits after the decimal point as milliseconds.
+        fn parse_millis(fraction: &str, index: usize) -> Result<u16, TimeParseError> {
+            let digit_count = fraction.len();
+            if digit_count == 0 || digit_count > MAX_FRACTION_DIGITS {
+                return Err(TimeParseError::InvalidNumber {
+                    segment: fraction.to_string(),
+                    index,
+                });
+            }
+
+            let digits: u16 = parse_number(fraction, index)?;
+            Ok(digits * 10u16.pow((MAX_FRACTION_DIGITS - digit_count) as u32))
+        }
+
+        fn parse_below_limit(
+            separator: &[&str],
+            index: usize,
+            component: TimeComponent,
+        ) -> Result<u64, TimeParseError> {
+            let value: u64 = parse_number(separator[index], index)?;
+            if value >= component.limit() {
+                return Err(TimeParseError::ComponentOutOfRange { component, value });
+            }
+
+            Ok(value)
+        }
+    }
+}
+
+impl<S: Numeric> Add for TimeInTime<S> {
+    type Output = TimeInTime<S>;
+
+    /// Panics if the sum of the seconds does not fit into the type of the seconds.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("Total seconds of a time must fit into its type")
+    }
+}
+
+impl<S: Numeric> Sub for TimeInTime<S> {
+    type Output = TimeInTime<S>;
+
+    /// Saturates at zero if the right side is the longer time.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .unwrap_or(TimeInTime::from_secs(S::ZERO))
+    }
+}
+
+/// Total seconds as time. `TryFrom<u64>` comes with this for free and never fails.
+impl From<u64> for TimeInTime {
+    fn from(total_secs: u64) -> Self {
+        TimeInTime::new(total_secs)
+    }
+}
+
+/// Panics like `Add` if the sum does not fit into the type of the seconds.
+impl<S: Numeric> Sum for TimeInTime<S> {
+    fn sum<I: Iterator<Item = TimeInTime<S>>>(iter: I) -> Self {
+        iter.fold(TimeInTime::from_secs(S::ZERO), Add::add)
+    }
+}
+
+impl<'a, S: Numeric> Sum<&'a TimeInTime<S>> for TimeInTime<S> {
+    fn sum<I: Iterator<Item = &'a TimeInTime<S>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl From<TimeInTime> for Duration {
+    fn from(time: TimeInTime) -> Self {
+        Duration::from_secs(time.total_secs) + Duration::from_millis(u64::from(time.frac_millis))
+    }
+}
+
+/// A duration with a precision finer than milliseconds can not become a `TimeInTime` without loss.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DurationConversionError {
+    /// Nanoseconds which would be lost.
+    pub lost_nanos: u32,
+}
+
+impl TryFrom<Duration> for TimeInTime {
+    type Error = DurationConversionError;
+
+    /// Fails if the duration has nanoseconds below the milliseconds.
+    /// Use `TimeInTime::from_duration_truncated` to drop them instead.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let lost_nanos = duration.subsec_nanos() % NANOS_PER_MILLI;
+        if lost_nanos != 0 {
+            return Err(DurationConversionError { lost_nanos });
+        }
+
+        Ok(TimeInTime::from_duration_truncated(duration))
+    }
+}
+
+const NANOS_PER_MILLI: u32 = 1_000_000;
+
+impl TimeInTime {
+    /// Drops the precision of the duration finer than milliseconds.
+    pub fn from_duration_truncated(duration: Duration) -> Self {
+        TimeInTime::new(duration.as_secs()).with_millis(duration.subsec_millis() as u16)
+    }
+}
+
+fn expect_fits(total_secs: Option<u64>) -> u64 {
+    total_secs.expect("Total seconds of a time must fit into u64")
+}
+
+/// Parses the segment at the index of a time as a positive number.
+/// Only ASCII digits are allowed, so a sign like in "+5" is rejected.
+fn parse_number<N: str::FromStr>(segment: &str, index: usize) -> Result<N, TimeParseError> {
+    let invalid = || TimeParseError::InvalidNumber {
+        segment: segment.to_string(),
+        index,
+    };
+    if !segment.bytes().all(|digit| digit.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    segment.parse().map_err(|_| invalid())
+}
+/// Shows HH:MM:SS or D:HH:MM:SS if there are days, followed by .mmm if there are milliseconds.
+/// Parsing the displayed text always returns an equal time again.
+impl fmt::Display for TimeInTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (days, hours, mins, secs) = self.get_days_hours_mins_secs();
+        if days == 0 {
+            write!(f, "{:02}:{:02}:{:02}", hours, mins, secs)?;
+        } else {
+            write!(f, "{}:{:02}:{:02}:{:02}", days, hours, mins, secs)?;
+        }
+
+        if self.frac_millis != 0 {
+            write!(f, ".{:03}", self.frac_millis)?;
+        }
+
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_display_with_zero_padded_fields() {
+        assert_eq!(
+            format!("{}", TimeInTime::with_hms(1, 2, 3)).trim(),
+            "01:02:03"
+        );
+        assert_eq!(
+            format!("{}", TimeInTime::with_hms(23, 20, 30)).trim(),
+            "23:20:30"
+        );
+    }
+
+    #[test]
+    fn should_display_without_trailing_newline() {
+        let displayed = format!("{}", TimeInTime::with_hms(12, 56, 12));
+
+        assert!(!displayed.ends_with('\n'));
+        assert_eq!(displayed, "12:56:12");
+    }
+
+    #[test]
+    fn should_parse_minutes_before_seconds() {
+        let parsed: TimeInTime = "05:30".parse().unwrap();
+
+        assert_eq!(parsed.total_secs, 330);
+    }
+
+    #[test]
+    fn should_reject_out_of_range_minutes_and_seconds() {
+        assert_eq!(
+            "1:60".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::ComponentOutOfRange {
+                component: TimeComponent::Seconds,
+                value: 60
+            }
+        );
+        assert_eq!(
+            "1:60:00".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::ComponentOutOfRange {
+                component: TimeComponent::Minutes,
+                value: 60
+            }
+        );
+    }
+
+    #[test]
+    fn should_parse_days() {
+        let parsed: TimeInTime = "2:03:04:05".parse().unwrap();
+
+        assert_eq!(parsed.get_days_hours_mins_secs(), (2, 3, 4, 5));
+        assert_eq!(parsed.get_days(), 2);
+        assert_eq!(
+            parsed.total_secs,
+            TimeInTime::with_dhms(2, 3, 4, 5).total_secs
+        );
+        assert_eq!(
+            "1:24:00:00".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::ComponentOutOfRange {
+                component: TimeComponent::Hours,
+                value: 24
+            }
+        );
+        assert_eq!(
+            "1:02:03:04:05".parse::<TimeInTime>(),
+            Err(TimeParseError::TooManySegments)
+        );
+    }
+
+    #[test]
+    fn should_display_days_only_if_not_zero() {
+        assert_eq!(TimeInTime::with_dhms(2, 3, 4, 5).to_string(), "2:03:04:05");
+        assert_eq!(TimeInTime::with_dhms(0, 23, 4, 5).to_string(), "23:04:05");
+    }
+
+    #[test]
+    fn should_round_trip_fractional_seconds() {
+        let parsed: TimeInTime = "00:00:01.250".parse().unwrap();
+
+        assert_eq!(parsed.total_secs, 1);
+        assert_eq!(parsed.get_millis(), 250);
+        assert_eq!(parsed.to_string(), "00:00:01.250");
+    }
+
+    #[test]
+    fn should_parse_one_up_to_three_fraction_digits() {
+        assert_eq!(
+            "01:02:03.5".parse::<TimeInTime>().unwrap().get_millis(),
+            500
+        );
+        assert_eq!(
+            "01:02:03.05".parse::<TimeInTime>().unwrap().get_millis(),
+            50
+        );
+        assert_eq!("3.007".parse::<TimeInTime>().unwrap().get_millis(), 7);
+        assert_eq!(
+            "01:02:03.5000".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::InvalidNumber {
+                segment: "5000".to_string(),
+                index: 2
+            }
+        );
+        assert_eq!(
+            "01:02:03.".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::InvalidNumber {
+                segment: "".to_string(),
+                index: 2
+            }
+        );
+        assert_eq!(
+            "01:02.5:03".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::InvalidNumber {
+                segment: "5:03".to_string(),
+                index: 1
+            }
+        );
+    }
+
+    #[test]
+    fn should_display_no_fraction_for_whole_seconds() {
+        assert_eq!(
+            "01:02:03.000".parse::<TimeInTime>().unwrap().to_string(),
+            "01:02:03"
+        );
+    }
+
+    #[test]
+    fn should_add_times() {
+        let sum = TimeInTime::with_ms(1, 30) + TimeInTime::with_ms(0, 45);
+        assert_eq!(sum.to_string(), "00:02:15");
+
+        let with_millis = TimeInTime::new(1).with_millis(600) + TimeInTime::new(0).with_millis(500);
+        assert_eq!(with_millis.to_string(), "00:00:02.100");
+    }
+
+    #[test]
+    fn should_subtract_times_saturating_at_zero() {
+        let difference = TimeInTime::with_ms(2, 15) - TimeInTime::with_ms(0, 45);
+        assert_eq!(difference.to_string(), "00:01:30");
+
+        let saturated = TimeInTime::with_ms(0, 45) - TimeInTime::with_ms(2, 15);
+        assert_eq!(saturated.to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn should_sort_times() {
+        let mut times: Vec<TimeInTime> = ["1:00", "0:30", "2:00"]
+            .iter()
+            .map(|time| time.parse().unwrap())
+            .collect();
+        times.sort();
+
+        assert_eq!(
+            times,
+            vec![
+                TimeInTime::with_ms(0, 30),
+                TimeInTime::with_ms(1, 0),
+                TimeInTime::with_ms(2, 0)
+            ]
+        );
+        assert!(TimeInTime::new(1).with_millis(1) > TimeInTime::new(1));
+    }
+
+    #[test]
+    fn should_report_overflow_of_total_seconds() {
+        assert_eq!(
+            "9999999999999999:0:0".parse::<TimeInTime>().unwrap_err(),
+            TimeParseError::Overflow
+        );
+        assert_eq!(
+            "999999999999999999:0:0:0"
+                .parse::<TimeInTime>()
+                .unwrap_err(),
+            TimeParseError::Overflow
+        );
+        assert_eq!(TimeInTime::checked_with_hms(u64::MAX / 3600, 59, 59), None);
+        assert_eq!(
+            TimeInTime::checked_with_hms(1, 2, 3),
+            Some(TimeInTime::with_hms(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn should_build_largest_time_from_units() {
+        let largest = TimeInTime::new(u64::MAX);
+
+        assert_eq!(TimeInTime::with_ms(u64::MAX / 60, u64::MAX % 60), largest);
+        assert_eq!(
+            TimeInTime::with_dhms(u64::MAX / 86_400, 0, 0, u64::MAX % 86_400),
+            largest
+        );
+        assert_eq!(
+            TimeInTime::checked_with_dhms(u64::MAX / 86_400 + 1, 0, 0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn should_keep_largest_seconds_with_millis_below_one_second() {
+        let time = TimeInTime::new(u64::MAX).with_millis(999);
+
+        assert_eq!((time.as_secs(), time.get_millis()), (u64::MAX, 999));
+        assert_eq!(
+            TimeInTime::new(u64::MAX - 1).with_millis(1500).as_secs(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Total seconds of a time must fit into u64")]
+    fn should_panic_on_overflowing_millis() {
+        TimeInTime::new(u64::MAX).with_millis(1500);
+    }
+
+    #[test]
+    #[should_panic(expected = "Total seconds of a time must fit into u64")]
+    fn should_panic_on_overflowing_units() {
+        TimeInTime::with_wdhms(u64::MAX / 7, 7, 0, 0, 0);
+    }
+
+    #[test]
+    fn should_parse_numbers_with_units() {
+        assert_eq!("1h30m".parse(), Ok(TimeInTime::with_hms(1, 30, 0)));
+        assert_eq!("45s".parse(), Ok(TimeInTime::new(45)));
+        assert_eq!("90s".parse(), Ok(TimeInTime::new(90)));
+        assert_eq!("1s2m3h".parse(), Ok(TimeInTime::with_hms(3, 2, 1)));
+        assert_eq!("1d1s".parse(), Ok(TimeInTime::with_dhms(1, 0, 0, 1)));
+    }
+
+    #[test]
+    fn should_reject_invalid_units() {
+        assert_eq!(
+            "1x".parse::<TimeInTime>(),
+            Err(TimeParseError::UnknownUnit('x'))
+        );
+        assert_eq!(
+            "1m2m".parse::<TimeInTime>(),
+            Err(TimeParseError::DuplicateUnit('m'))
+        );
+        assert_eq!(parse_units("1h30"), Err(TimeParseError::MissingUnit));
+        assert_eq!(
+            "1h30".parse::<TimeInTime>(),
+            Err(TimeParseError::MissingUnit)
+        );
+        assert_eq!(
+            "h".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "".to_string(),
+                index: 0
+            })
+        );
+        assert_eq!(
+            "1h-2m".parse::<TimeInTime>(),
+            Err(TimeParseError::UnknownUnit('-'))
+        );
+        assert_eq!(
+            "1h1dm".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "".to_string(),
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn should_round_trip_with_duration() {
+        for duration in [
+            Duration::ZERO,
+            Duration::from_millis(1_250),
+            Duration::from_secs(90),
+            Duration::from_secs(u64::MAX),
+        ] {
+            let time = TimeInTime::try_from(duration).unwrap();
+            assert_eq!(Duration::from(time), duration);
+        }
+    }
+
+    #[test]
+    fn should_convert_multi_hour_duration() {
+        let time = TimeInTime::try_from(Duration::from_secs(3 * 3600 + 25 * 60 + 7)).unwrap();
+
+        assert_eq!(time, TimeInTime::with_hms(3, 25, 7));
+        assert_eq!(
+            Duration::from(TimeInTime::with_hms(5, 0, 1)),
+            Duration::from_secs(5 * 3600 + 1)
+        );
+    }
+
+    #[test]
+    fn should_not_convert_duration_finer_than_millis() {
+        let duration = Duration::new(2, 3_000_004);
+
+        assert_eq!(
+            TimeInTime::try_from(duration),
+            Err(DurationConversionError { lost_nanos: 4 })
+        );
+        assert_eq!(
+            TimeInTime::from_duration_truncated(duration),
+            TimeInTime::new(2).with_millis(3)
+        );
+    }
+
+    #[test]
+    fn should_return_totals_distinct_from_components() {
+        let time = TimeInTime::with_hms(1, 30, 0);
+
+        assert_eq!(time.as_minutes(), 90);
+        assert_eq!(time.get_minutes(), 30);
+        assert_eq!(time.as_secs(), 5400);
+        assert_eq!(time.get_secs(), 0);
+        assert_eq!(TimeInTime::with_hms(25, 59, 59).as_hours(), 25);
+    }
+
+    #[test]
+    fn should_return_time_between_in_both_orders() {
+        let later: TimeInTime = "1:00:00".parse().unwrap();
+        let earlier: TimeInTime = "0:45:00".parse().unwrap();
+        let expected: TimeInTime = "0:15:00".parse().unwrap();
+
+        assert_eq!(TimeInTime::between(&later, &earlier), expected);
+        assert_eq!(TimeInTime::between(&earlier, &later), expected);
+        assert_eq!(TimeInTime::between(&later, &later), TimeInTime::new(0));
+    }
+
+    #[test]
+    fn should_carry_milliseconds_with_any_width() {
+        let narrow = TimeInTime::from_secs(10u8).checked_add(TimeInTime::from_secs(5u8));
+        let half = TimeInTime::new(1).with_millis(500);
+
+        assert_eq!(narrow.map(|time| time.as_secs()), Some(15));
+        assert_eq!(half + half, TimeInTime::new(3));
+        assert_eq!(
+            TimeInTime::new(3) - half,
+            TimeInTime::new(1).with_millis(500)
+        );
+        assert_eq!(
+            TimeInTime::from_secs(u32::MAX).checked_add(TimeInTime::from_secs(1)),
+            None
+        );
+        assert_eq!(
+            TimeInTime::from_secs(1u128).checked_sub(TimeInTime::from_secs(2)),
+            None
+        );
+        assert_eq!(half.checked_sub(TimeInTime::new(1).with_millis(501)), None);
+    }
+
+    #[test]
+    fn should_sum_times_with_u32_seconds() {
+        let times = [TimeInTime::from_secs(40u32), TimeInTime::from_secs(20u32)];
+
+        assert_eq!(
+            times.iter().sum::<TimeInTime<u32>>(),
+            TimeInTime::from_secs(60)
+        );
+    }
+
+    #[test]
+    fn should_sum_times() {
+        let times: Vec<TimeInTime> = ["0:30", "0:45", "1:00"]
+            .iter()
+            .map(|time| time.parse().unwrap())
+            .collect();
+        let expected: TimeInTime = "2:15".parse().unwrap();
+
+        assert_eq!(times.iter().sum::<TimeInTime>(), expected);
+        assert_eq!(times.into_iter().sum::<TimeInTime>(), expected);
+        assert_eq!(
+            Vec::<TimeInTime>::new().into_iter().sum::<TimeInTime>(),
+            TimeInTime::new(0)
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_segment_with_index() {
+        assert_eq!(
+            "1:ab:3".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "ab".to_string(),
+                index: 1
+            })
+        );
+        assert_eq!(
+            "1:#:3".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "#".to_string(),
+                index: 1
+            })
+        );
+        assert_eq!(
+            "-5".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "-5".to_string(),
+                index: 0
+            })
+        );
+        assert_eq!(
+            "+5:+3".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "+5".to_string(),
+                index: 0
+            })
+        );
+        assert_eq!(
+            "5:+3".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "+3".to_string(),
+                index: 1
+            })
+        );
+        assert_eq!(
+            "01:02:03.+5".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "+5".to_string(),
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn should_display_readable_parse_errors() {
+        assert_eq!(
+            TimeParseError::TooManySegments.to_string(),
+            "time has more segments than days, hours, minutes and seconds"
+        );
+        assert_eq!(
+            "1:60".parse::<TimeInTime>().unwrap_err().to_string(),
+            "seconds must be below 60 but is 60"
+        );
+    }
+
+    #[test]
+    fn should_propagate_parse_error_as_boxed_error() {
+        fn parse_boxed(text: &str) -> Result<TimeInTime, Box<dyn std::error::Error>> {
+            Ok(text.parse()?)
+        }
+
+        assert!(parse_boxed("1:ab").is_err());
+        assert_eq!(parse_boxed("1:02").unwrap(), TimeInTime::with_ms(1, 2));
+    }
+
+    #[test]
+    fn should_humanize_times() {
+        assert_eq!(TimeInTime::with_hms(1, 0, 0).humanize(), "1 hour");
+        assert_eq!(
+            TimeInTime::with_hms(1, 2, 3).humanize(),
+            "1 hour, 2 minutes, 3 seconds"
+        );
+        assert_eq!(
+            TimeInTime::with_dhms(2, 0, 1, 0).humanize(),
+            "2 days, 1 minute"
+        );
+        assert_eq!(TimeInTime::new(0).humanize(), "0 seconds");
+    }
+
+    #[test]
+    fn should_create_from_total_seconds() {
+        assert_eq!(
+            TimeInTime::from(3661u64),
+            "1:01:01".parse::<TimeInTime>().unwrap()
+        );
+        assert_eq!(TimeInTime::from(0u64), TimeInTime::ZERO);
+    }
+
+    #[test]
+    fn should_saturate_instead_of_overflow() {
+        assert_eq!(
+            TimeInTime::saturating_with_hms(u64::MAX, 1, 1),
+            TimeInTime::new(u64::MAX)
+        );
+        assert_eq!(
+            TimeInTime::saturating_with_hms(1, 2, 3),
+            TimeInTime::with_hms(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn should_format_in_12_hour_form() {
+        assert_eq!(TimeInTime::with_hms(0, 0, 0).format_12h(), "12:00:00 AM");
+        assert_eq!(TimeInTime::with_hms(12, 0, 0).format_12h(), "12:00:00 PM");
+        assert_eq!(TimeInTime::with_hms(13, 5, 9).format_12h(), "01:05:09 PM");
+        assert_eq!(TimeInTime::with_hms(11, 59, 59).format_12h(), "11:59:59 AM");
+        assert_eq!(TimeInTime::with_hms(24, 0, 0).format_12h(), "12:00:00 AM");
+    }
+
+    #[test]
+    fn should_ignore_surrounding_whitespace() {
+        assert_eq!(" 12:56:12 ".parse(), Ok(TimeInTime::with_hms(12, 56, 12)));
+        assert_eq!("\t1h30m\n".parse(), Ok(TimeInTime::with_hms(1, 30, 0)));
+    }
+
+    #[test]
+    fn should_reject_whitespace_within_segments() {
+        assert_eq!(
+            "1 : 2".parse::<TimeInTime>(),
+            Err(TimeParseError::InvalidNumber {
+                segment: "1 ".to_string(),
+                index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn should_round_trip_between_display_and_parse() {
+        let interesting_secs = [0, 1, 59, 60, 3599, 3600, 86_399, 86_400, u64::MAX];
+        let many_secs = (0..200_000)
+            .step_by(7)
+            .chain((0..64).map(|shift| 1u64 << shift));
+
+        for total_secs in interesting_secs.into_iter().chain(many_secs) {
+            for millis in [0, 1, 250, 999] {
+                let time = TimeInTime::new(total_secs).with_millis(millis);
+                let displayed = time.to_string();
+
+                assert_eq!(
+                    displayed.parse::<TimeInTime>(),
+                    Ok(time),
+                    "No round trip via {displayed:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_deduplicate_times_in_hash_set() {
+        let times: std::collections::HashSet<TimeInTime> = ["1:00", "60", "1m", "0:30", "30s"]
+            .iter()
+            .map(|time| time.parse().unwrap())
+            .collect();
+
+        assert_eq!(times.len(), 2);
+        assert!(times.contains(&TimeInTime::new(60)));
+        assert!(times.contains(&TimeInTime::new(30)));
+    }
+
+    #[test]
+    fn should_copy_and_default_to_zero() {
+        let time = TimeInTime::with_ms(1, 2);
+        let copied = time;
+
+        assert_eq!(time, copied);
+        assert_eq!(TimeInTime::default(), TimeInTime::ZERO);
+    }
+
+    #[test]
+    fn should_parse_and_show_weeks() {
+        let week: TimeInTime = "1w".parse().unwrap();
+
+        assert_eq!(week.as_secs(), 604_800);
+        assert_eq!(week.get_weeks(), 1);
+        assert_eq!("2w3d".parse(), Ok(TimeInTime::with_wdhms(2, 3, 0, 0, 0)));
+        assert_eq!(
+            TimeInTime::with_wdhms(1, 1, 1, 0, 0).humanize(),
+            "1 week, 1 day, 1 hour"
+        );
+    }
+
+    #[test]
+    fn should_return_percent_of_day() {
+        assert_eq!(
+            "12:00:00".parse::<TimeInTime>().unwrap().percent_of_day(),
+            50.0
+        );
+        assert_eq!(TimeInTime::ZERO.percent_of_day(), 0.0);
+        assert_eq!(TimeInTime::with_dhms(1, 12, 0, 0).percent_of_day(), 150.0);
+    }
+
+    #[test]
+    fn should_report_empty_input() {
+        assert_eq!("".parse::<TimeInTime>(), Err(TimeParseError::Empty));
+        assert_eq!(" \t\n".parse::<TimeInTime>(), Err(TimeParseError::Empty));
+        assert_eq!(parse_units(""), Err(TimeParseError::Empty));
+    }
+
+    #[test]
+    fn should_still_parse_valid_times() {
+        assert_eq!("1:59".parse::<TimeInTime>().unwrap().total_secs, 119);
+        assert_eq!(
+            "100:59:00".parse::<TimeInTime>().unwrap().total_secs,
+            363_540
+        );
+        assert_eq!("3600".parse::<TimeInTime>().unwrap().total_secs, 3600);
+    }
+}
@@ -0,0 +1,49 @@
+//! With the feature serde a time is serialized as text via `Display`
+//! and deserialized via `FromStr`, for example "01:02:03" in JSON.
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::TimeInTime;
+
+impl Serialize for TimeInTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeInTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse()
+            .map_err(|error| de::Error::custom(format_args!("invalid time {text:?}: {error}")))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        name: String,
+        timeout: TimeInTime,
+    }
+
+    #[test]
+    fn should_round_trip_through_json() {
+        let config = Config {
+            name: "backup".to_string(),
+            timeout: TimeInTime::with_hms(1, 2, 3).with_millis(40),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"name":"backup","timeout":"01:02:03.040"}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn should_reject_invalid_time_in_json() {
+        let result = serde_json::from_str::<Config>(r#"{"name":"backup","timeout":"1:99"}"#);
+
+        assert!(result.is_err());
+    }
+}
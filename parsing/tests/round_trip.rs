@@ -0,0 +1,24 @@
+use parsing::fuzzing::{check_parse_never_panics, check_round_trip, time_from_parts};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn should_parse_displayed_time_back(total_secs in any::<u64>(), millis in 0u16..1000) {
+        check_round_trip(time_from_parts(total_secs, millis));
+    }
+
+    #[test]
+    fn should_parse_displayed_time_below_days_back(total_secs in 0u64..200_000, millis in 0u16..1000) {
+        check_round_trip(time_from_parts(total_secs, millis));
+    }
+
+    #[test]
+    fn should_not_panic_on_arbitrary_bytes(data in proptest::collection::vec(any::<u8>(), 0..64)) {
+        check_parse_never_panics(&data);
+    }
+
+    #[test]
+    fn should_not_panic_on_time_like_text(text in "[0-9:.hmsdw \\-+]{0,24}") {
+        check_parse_never_panics(text.as_bytes());
+    }
+}
@@ -0,0 +1,44 @@
+//! One crate to use all crates of this show case.
+//! Every crate is available as module under its own name.
+//! The most used types and traits are in the prelude.
+//! ```
+//! use show_case::prelude::*;
+//!
+//! let time: TimeInTime = "1:02:03".parse().unwrap();
+//! let hopped: Vec<_> = (1..20).hopp(2, 3).collect();
+//!
+//! assert_eq!(time.as_secs(), 3723);
+//! assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+//! ```
+pub use change_calc;
+pub use hopper_iter;
+pub use impl_traits_on_extern_types;
+pub use numeric;
+pub use parsing;
+pub use the_answer_to_everything;
+pub use tree_of_madness;
+
+pub mod report;
+
+/// Everything for `use show_case::prelude::*;`.
+///
+/// The extension traits make their methods callable on the types of other crates:
+/// - [`HopperExt`]: `hopp` and friends on every iterator.
+/// - [`Report`]: `report` on a change, a time and a tree.
+/// - [`HasDataWithLength`]: `get_data_with_length` on every type with Display.
+/// - [`Numeric`]: `checked_add` and `checked_sub` in code generic over the integer width.
+///
+/// [`NodeRef`] is the bound to write code generic over the pointers of a [`Tree`].
+/// The remaining items are the most used functions, types and errors of each crate.
+pub mod prelude {
+    pub use crate::report::{print_report, Report};
+    pub use change_calc::{calc_change, parse_coins, ChangeWithLeft, Money, SpecError};
+    pub use hopper_iter::{HopPhase, Hopper, HopperError, HopperExt};
+    pub use impl_traits_on_extern_types::{format_count_with_data, HasDataWithLength};
+    pub use numeric::Numeric;
+    pub use parsing::{TimeInTime, TimeParseError};
+    pub use the_answer_to_everything::test_if_you_know_purpose_of_life;
+    pub use tree_of_madness::build_tree;
+    pub use tree_of_madness::node_ref::NodeRef;
+    pub use tree_of_madness::tree::{Tree, TreeError};
+}
@@ -0,0 +1,90 @@
+use clap::{Parser, Subcommand};
+use show_case::prelude::*;
+use std::process::ExitCode;
+
+/// Calls the crates of this show case from the command line.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Calculates the change for an amount.
+    Change {
+        #[arg(long)]
+        amount: u32,
+        /// Coins separated by commas, for example 50,20,10.
+        #[arg(long)]
+        coins: String,
+    },
+    /// Works with a time.
+    Time {
+        #[command(subcommand)]
+        command: TimeCommand,
+    },
+    /// Works with a binary search tree.
+    Tree {
+        #[command(subcommand)]
+        command: TreeCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum TimeCommand {
+    /// Parses a time like 1:02:03 or 1h2m3s and prints it.
+    Parse { time: String },
+}
+
+#[derive(Subcommand)]
+enum TreeCommand {
+    /// Adds values to a new tree.
+    Add {
+        #[arg(allow_negative_numbers = true)]
+        values: Vec<i64>,
+        /// Prints the values of the tree level by level.
+        #[arg(long)]
+        print: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<String, Box<dyn std::error::Error>> {
+    let output = match command {
+        Command::Change { amount, coins } => {
+            let ChangeWithLeft(change, left) = calc_change(amount, &parse_coins(&coins)?);
+            format!("Change: {change}\nLeft: {left}")
+        }
+        Command::Time {
+            command: TimeCommand::Parse { time },
+        } => time.parse::<TimeInTime>()?.to_string(),
+        Command::Tree {
+            command: TreeCommand::Add { values, print },
+        } => {
+            let mut tree = Tree::new();
+            let added = values.into_iter().filter(|&value| tree.add(value)).count();
+            let mut output = format!("Added: {added}");
+            if print {
+                let values: Vec<_> = tree.iter_shared().map(|value| value.to_string()).collect();
+                output.push_str(&format!("\nValues: {}", values.join(" ")));
+            }
+            output
+        }
+    };
+
+    Ok(output)
+}
@@ -0,0 +1,74 @@
+//! All crates of this show case compute something. A report describes the outcome as text.
+use change_calc::ChangeWithLeft;
+use parsing::TimeInTime;
+use std::fmt::Display;
+use tree_of_madness::tree::Tree;
+
+/// Describes a value as text for humans.
+pub trait Report {
+    fn report(&self) -> String;
+}
+
+/// Prints the report of any type which implements [`Report`].
+pub fn print_report<R: Report + ?Sized>(to_report: &R) {
+    println!("{}", to_report.report());
+}
+
+impl<N: Display> Report for ChangeWithLeft<N> {
+    fn report(&self) -> String {
+        format!("Change: {}, left: {}", self.as_grouped_string(), self.1)
+    }
+}
+
+impl Report for TimeInTime {
+    fn report(&self) -> String {
+        format!("{self} ({})", self.humanize())
+    }
+}
+
+/// Lists the values level by level from the root.
+impl<T: Display> Report for Tree<T> {
+    fn report(&self) -> String {
+        let values: Vec<_> = self.iter_shared().map(|value| value.to_string()).collect();
+        format!("Tree with {} values: {}", values.len(), values.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use tree_of_madness::build_tree;
+
+    #[test]
+    fn should_report_change() {
+        let change = ChangeWithLeft("50 50 10".to_string(), 3);
+
+        assert_eq!(change.report(), "Change: 2x50 1x10, left: 3");
+    }
+
+    #[test]
+    fn should_report_time() {
+        let time = TimeInTime::with_hms(1, 0, 3);
+
+        assert_eq!(time.report(), "01:00:03 (1 hour, 3 seconds)");
+    }
+
+    #[test]
+    fn should_report_tree() {
+        let tree = build_tree![5, 3, 8, 4];
+
+        assert_eq!(tree.report(), "Tree with 4 values: 5 3 8 4");
+        assert_eq!(Tree::<u8>::new().report(), "Tree with 0 values: ");
+    }
+
+    #[test]
+    fn should_report_via_trait_object() {
+        let reports: Vec<Box<dyn Report>> = vec![
+            Box::new(TimeInTime::new(0)),
+            Box::new(ChangeWithLeft(String::new(), 5)),
+        ];
+        let texts: Vec<_> = reports.iter().map(|report| report.report()).collect();
+
+        assert_eq!(texts, vec!["00:00:00 (0 seconds)", "Change: , left: 5"]);
+    }
+}
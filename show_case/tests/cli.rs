@@ -0,0 +1,46 @@
+use std::process::{Command, Output};
+
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_show_case"))
+        .args(args)
+        .output()
+        .expect("Could not start the cli")
+}
+
+fn stdout_of(args: &[&str]) -> String {
+    let output = run_cli(args);
+    assert!(output.status.success(), "Failed with {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn should_calc_change() {
+    assert_eq!(
+        stdout_of(&["change", "--amount", "238", "--coins", "50,20,10"]),
+        "Change: 50 50 50 50 20 10\nLeft: 8\n"
+    );
+}
+
+#[test]
+fn should_parse_time() {
+    assert_eq!(stdout_of(&["time", "parse", "1:02:03"]), "01:02:03\n");
+}
+
+#[test]
+fn should_add_to_tree_and_print() {
+    assert_eq!(
+        stdout_of(&["tree", "add", "5", "3", "8", "3", "--print"]),
+        "Added: 3\nValues: 5 3 8\n"
+    );
+    assert_eq!(stdout_of(&["tree", "add", "5", "3"]), "Added: 2\n");
+}
+
+#[test]
+fn should_fail_for_invalid_input() {
+    let output = run_cli(&["change", "--amount", "238", "--coins", "50,x"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Error: "));
+}
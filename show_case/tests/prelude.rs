@@ -0,0 +1,56 @@
+use show_case::prelude::*;
+use std::collections::HashSet;
+
+#[test]
+fn should_use_every_crate_through_prelude() {
+    let coins: HashSet<u32> = parse_coins("50,20,10").unwrap();
+    assert_eq!(
+        calc_change(80, &coins),
+        ChangeWithLeft("50 20 10".to_string(), 0)
+    );
+
+    let hopped: Vec<_> = (1..20).hopp(2, 3).collect();
+    assert_eq!(hopped, vec![1, 2, 6, 7, 11, 12, 16, 17]);
+
+    let time: TimeInTime = "1:02:03".parse().unwrap();
+    assert_eq!(time, TimeInTime::with_hms(1, 2, 3));
+
+    let tree = build_tree![10, 3, 16];
+    assert!(tree.contains(&3));
+
+    assert_eq!("hi".get_data_with_length(), (2, String::from("hi")));
+    assert_eq!(test_if_you_know_purpose_of_life(42), Ok(()));
+}
+
+#[test]
+fn should_call_extension_methods_after_glob_import() {
+    let hopped: Vec<u32> = [1, 2, 3, 4, 5, 6].into_iter().hopp(2, 3).collect();
+    assert_eq!(hopped, vec![1, 2, 6]);
+
+    assert!(TimeInTime::with_hms(0, 1, 0)
+        .report()
+        .starts_with("00:01:00"));
+    assert_eq!(build_tree![2, 1].report(), "Tree with 2 values: 2 1");
+
+    let boxed: Box<dyn Report> = Box::new(ChangeWithLeft("5 5".to_string(), 0));
+    assert_eq!(boxed.report(), "Change: 2x5, left: 0");
+
+    assert_eq!(Numeric::checked_sub(1u8, 2), None);
+}
+
+fn count_values<T: Ord, P: NodeRef>(tree: &Tree<T, P>) -> usize {
+    tree.len()
+}
+
+#[test]
+fn should_write_generic_tree_code_with_prelude() {
+    assert_eq!(count_values(&build_tree![3, 1, 2]), 3);
+}
+
+#[test]
+fn should_reach_crates_as_modules() {
+    assert!(show_case::change_calc::can_always_make_change(
+        &[1, 5].into_iter().collect()
+    ));
+    assert_eq!(show_case::the_answer_to_everything::THE_TRUTH, 42);
+}
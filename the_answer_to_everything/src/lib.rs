@@ -0,0 +1,591 @@
+//! Shows simple macros for early returns and loop control.
+pub const THE_TRUTH: u32 = 42;
+pub const EXPLAINING_WELL_KNOW: &str = "The answer is 42, you fool !";
+pub fn test_if_you_know_purpose_of_life(answer: u32) -> Result<(), &'static str> {
+    return_err_if!(answer != THE_TRUTH, EXPLAINING_WELL_KNOW);
+    Ok(())
+}
+
+/// Returns `ret_val` if `to_test == cond`.
+/// Works for any types which can be compared via PartialEq, not just numbers.
+/// `to_test` is evaluated once and only borrowed.
+#[macro_export]
+macro_rules! return_if_with {
+    ($to_test:expr, $cond:expr, $ret_val:expr) => {{
+        // Borrowed to evaluate the expression only once without moving out of it.
+        let to_test = &$to_test;
+        if *to_test == $cond {
+            return $ret_val;
+        }
+    }};
+}
+
+/// Like [`return_if_with`] but notes the early return before returning.
+/// Without a logger the note is printed to stderr in debug builds only,
+/// so release builds stay quiet.
+/// With a logger as fourth argument, it is called with the note instead, in every build.
+#[macro_export]
+macro_rules! return_if_with_log {
+    ($to_test:expr, $cond:expr, $ret_val:expr) => {{
+        $crate::return_if_with_log!($to_test, $cond, $ret_val, |note: &str| {
+            if cfg!(debug_assertions) {
+                eprintln!("{note}");
+            }
+        })
+    }};
+    ($to_test:expr, $cond:expr, $ret_val:expr, $logger:expr) => {{
+        let to_test = &$to_test;
+        if *to_test == $cond {
+            let note = format!(
+                "Early return at {}:{} because {} == {}",
+                file!(),
+                line!(),
+                stringify!($to_test),
+                stringify!($cond)
+            );
+            ($logger)(note.as_str());
+            return $ret_val;
+        }
+    }};
+}
+
+/// Returns early if the precondition does not hold.
+/// Forms: `guard!(cond, else return)` and `guard!(cond, else return value)`.
+#[macro_export]
+macro_rules! guard {
+    ($cond:expr, else return) => {{
+        if !$cond {
+            return;
+        }
+    }};
+    ($cond:expr, else return $ret:expr) => {{
+        if !$cond {
+            return $ret;
+        }
+    }};
+}
+
+/// Accepts any answer within the truth plus or minus the tolerance.
+pub fn test_with_tolerance(answer: u32, tolerance: u32) -> Result<(), &'static str> {
+    let close_to_truth = THE_TRUTH.saturating_sub(tolerance)..=THE_TRUTH.saturating_add(tolerance);
+    return_if_within!(answer, close_to_truth, Ok(()));
+    Err(EXPLAINING_WELL_KNOW)
+}
+
+/// Returns `ret_val` if `to_test` is within the range.
+/// Works for any range with `contains` like `1..=3`, `..10` or `5..`.
+/// ```
+/// use the_answer_to_everything::return_if_within;
+///
+/// fn is_teen(age: u32) -> bool {
+///     return_if_within!(age, 13..=19, true);
+///     false
+/// }
+///
+/// assert!(is_teen(15));
+/// assert!(!is_teen(20));
+/// ```
+#[macro_export]
+macro_rules! return_if_within {
+    ($to_test:expr, $range:expr, $ret_val:expr) => {{
+        if ($range).contains(&$to_test) {
+            return $ret_val;
+        }
+    }};
+}
+
+/// Opposite of [`return_if_with`], returns `ret_val` if `to_test != cond`.
+/// `to_test` is evaluated once and only borrowed.
+/// ```
+/// use the_answer_to_everything::return_unless;
+///
+/// fn check(answer: u32) -> Result<(), &'static str> {
+///     return_unless!(answer, 42, Err("wrong answer"));
+///     Ok(())
+/// }
+///
+/// assert_eq!(check(42), Ok(()));
+/// assert_eq!(check(7), Err("wrong answer"));
+/// ```
+#[macro_export]
+macro_rules! return_unless {
+    ($to_test:expr, $cond:expr, $ret_val:expr) => {{
+        let to_test = &$to_test;
+        if *to_test != $cond {
+            return $ret_val;
+        }
+    }};
+}
+
+/// Always returns, `then` if the condition holds and `else` otherwise.
+/// Fits as the last statement of a function.
+/// ```
+/// use the_answer_to_everything::return_if_else;
+///
+/// fn sign(number: i32) -> &'static str {
+///     return_if_else!(number < 0, "negative", "positive");
+/// }
+///
+/// assert_eq!(sign(-3), "negative");
+/// assert_eq!(sign(3), "positive");
+/// ```
+#[macro_export]
+macro_rules! return_if_else {
+    ($cond:expr, $then:expr, $else:expr) => {{
+        return if $cond { $then } else { $else };
+    }};
+}
+
+/// Returns `Ok(val)` if the condition holds.
+/// ```
+/// use the_answer_to_everything::return_ok_if;
+///
+/// fn parse_or_one(text: &str) -> Result<u32, std::num::ParseIntError> {
+///     return_ok_if!(text.is_empty(), 1);
+///     text.parse()
+/// }
+///
+/// assert_eq!(parse_or_one(""), Ok(1));
+/// assert_eq!(parse_or_one("3"), Ok(3));
+/// ```
+#[macro_export]
+macro_rules! return_ok_if {
+    ($cond:expr, $val:expr) => {{
+        if $cond {
+            return Ok($val);
+        }
+    }};
+}
+
+/// Returns `Err(err)` if the condition holds.
+/// ```
+/// use the_answer_to_everything::return_err_if;
+///
+/// fn halve(number: u32) -> Result<u32, &'static str> {
+///     return_err_if!(number % 2 != 0, "odd number");
+///     Ok(number / 2)
+/// }
+///
+/// assert_eq!(halve(8), Ok(4));
+/// assert_eq!(halve(7), Err("odd number"));
+/// ```
+#[macro_export]
+macro_rules! return_err_if {
+    ($cond:expr, $err:expr) => {{
+        if $cond {
+            return Err($err);
+        }
+    }};
+}
+
+/// Breaks out of a loop if the condition holds.
+/// Forms: `break_if!(cond)`, `break_if!(cond => value)`,
+/// `break_if!(cond, 'label)` and `break_if!(cond, 'label, value)`.
+#[macro_export]
+macro_rules! break_if {
+    ($cond:expr) => {{
+        if $cond {
+            break;
+        }
+    }};
+    ($cond:expr => $val:expr) => {{
+        if $cond {
+            break $val;
+        }
+    }};
+    ($cond:expr, $label:lifetime) => {{
+        if $cond {
+            break $label;
+        }
+    }};
+    ($cond:expr, $label:lifetime, $val:expr) => {{
+        if $cond {
+            break $label $val;
+        }
+    }};
+}
+
+/// Continues with the next loop iteration if the condition holds.
+/// Forms: `continue_if!(cond)` and `continue_if!(cond, 'label)`.
+#[macro_export]
+macro_rules! continue_if {
+    ($cond:expr) => {{
+        if $cond {
+            continue;
+        }
+    }};
+    ($cond:expr, $label:lifetime) => {{
+        if $cond {
+            continue $label;
+        }
+    }};
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn explain_unless_truth(answer: u32) -> Result<(), &'static str> {
+        return_unless!(answer, THE_TRUTH, Err(EXPLAINING_WELL_KNOW));
+        Ok(())
+    }
+
+    struct Guess {
+        answer: u32,
+    }
+
+    impl Guess {
+        fn check(&self) -> Result<(), &'static str> {
+            return_if_with!(self.answer, THE_TRUTH, Ok(()));
+            Err(EXPLAINING_WELL_KNOW)
+        }
+    }
+
+    fn check_doubled(half: u32, calls: &mut u32) -> Result<(), &'static str> {
+        let mut double = |value: u32| {
+            *calls += 1;
+            value * 2
+        };
+        return_if_with!(double(half), THE_TRUTH, Ok(()));
+        Err(EXPLAINING_WELL_KNOW)
+    }
+
+    #[derive(PartialEq)]
+    enum Answer {
+        Truth,
+        Ignorance,
+    }
+
+    fn check_text(answer: String) -> bool {
+        return_if_with!(answer, "forty-two", true);
+        false
+    }
+
+    fn check_enum(answer: Answer) -> bool {
+        return_if_with!(answer, Answer::Truth, true);
+        false
+    }
+
+    fn check_with_log(answer: u32, notes: &mut Vec<String>) -> Result<(), &'static str> {
+        return_if_with_log!(answer, THE_TRUTH, Ok(()), |note: &str| notes
+            .push(note.to_string()));
+        Err(EXPLAINING_WELL_KNOW)
+    }
+
+    fn check_with_default_log(answer: u32) -> Result<(), &'static str> {
+        return_if_with_log!(answer, THE_TRUTH, Ok(()));
+        Err(EXPLAINING_WELL_KNOW)
+    }
+
+    #[test]
+    fn should_return_and_log_if_equal() {
+        let mut notes = Vec::new();
+        assert_eq!(check_with_log(42, &mut notes), Ok(()));
+        assert_eq!(check_with_log(41, &mut notes), Err(EXPLAINING_WELL_KNOW));
+
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].contains("answer == THE_TRUTH"), "{}", notes[0]);
+        assert_eq!(check_with_default_log(42), Ok(()));
+    }
+
+    fn check_with_log_in_match(answer: Option<u32>) -> Result<(), &'static str> {
+        match answer {
+            Some(answer) => return_if_with_log!(answer, THE_TRUTH, Ok(()), |_: &str| ()),
+            None => return Err("No answer"),
+        }
+        Err(EXPLAINING_WELL_KNOW)
+    }
+
+    #[test]
+    fn should_return_and_log_in_expression_position() {
+        assert_eq!(check_with_log_in_match(Some(42)), Ok(()));
+        assert_eq!(check_with_log_in_match(Some(41)), Err(EXPLAINING_WELL_KNOW));
+        assert_eq!(check_with_log_in_match(None), Err("No answer"));
+    }
+
+    fn divide_truth(divisor: u32) -> Result<u32, &'static str> {
+        guard!(divisor != 0, else return Err("Division by zero"));
+        Ok(THE_TRUTH / divisor)
+    }
+
+    fn count_divisions(divisor: u32, count: &mut u32) {
+        guard!(divisor != 0, else return);
+        *count += 1;
+    }
+
+    #[test]
+    fn should_guard_against_zero_with_value() {
+        assert_eq!(divide_truth(0), Err("Division by zero"));
+        assert_eq!(divide_truth(2), Ok(21));
+    }
+
+    fn divide_truth_if_given(divisor: Option<u32>) -> Result<u32, &'static str> {
+        match divisor {
+            Some(divisor) => guard!(divisor != 0, else return Err("Division by zero")),
+            None => guard!(false, else return Ok(THE_TRUTH)),
+        }
+        Ok(THE_TRUTH / divisor.unwrap_or(1))
+    }
+
+    #[test]
+    fn should_guard_in_expression_position() {
+        assert_eq!(divide_truth_if_given(Some(0)), Err("Division by zero"));
+        assert_eq!(divide_truth_if_given(Some(2)), Ok(21));
+        assert_eq!(divide_truth_if_given(None), Ok(THE_TRUTH));
+    }
+
+    #[test]
+    fn should_guard_against_zero_without_value() {
+        let mut count = 0;
+        count_divisions(0, &mut count);
+        count_divisions(3, &mut count);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn should_return_if_string_equal() {
+        assert!(check_text(String::from("forty-two")));
+        assert!(!check_text(String::from("forty-one")));
+    }
+
+    #[test]
+    fn should_return_if_enum_equal() {
+        assert!(check_enum(Answer::Truth));
+        assert!(!check_enum(Answer::Ignorance));
+    }
+
+    #[test]
+    fn should_return_if_field_equal() {
+        assert_eq!(Guess { answer: 42 }.check(), Ok(()));
+        assert_eq!(Guess { answer: 7 }.check(), Err(EXPLAINING_WELL_KNOW));
+    }
+
+    fn check_in_match(answer: Option<u32>) -> Result<(), &'static str> {
+        match answer {
+            Some(answer) => return_if_with!(answer, THE_TRUTH, Ok(())),
+            None => return Err("No answer"),
+        }
+        Err(EXPLAINING_WELL_KNOW)
+    }
+
+    #[test]
+    fn should_return_if_equal_in_expression_position() {
+        assert_eq!(check_in_match(Some(42)), Ok(()));
+        assert_eq!(check_in_match(Some(41)), Err(EXPLAINING_WELL_KNOW));
+        assert_eq!(check_in_match(None), Err("No answer"));
+    }
+
+    #[test]
+    fn should_return_if_call_equal_with_one_call() {
+        let mut calls = 0;
+        assert_eq!(check_doubled(21, &mut calls), Ok(()));
+        assert_eq!(check_doubled(20, &mut calls), Err(EXPLAINING_WELL_KNOW));
+        assert_eq!(calls, 2);
+    }
+
+    fn check_with_if_else(answer: u32) -> Result<(), &'static str> {
+        return_if_else!(answer == THE_TRUTH, Ok(()), Err(EXPLAINING_WELL_KNOW));
+    }
+
+    #[test]
+    fn should_return_then_if_true() {
+        assert_eq!(check_with_if_else(42), Ok(()));
+    }
+
+    #[test]
+    fn should_return_else_if_false() {
+        assert_eq!(check_with_if_else(0), Err(EXPLAINING_WELL_KNOW));
+    }
+
+    fn check_with_if_else_in_match(answer: Option<u32>) -> Result<(), &'static str> {
+        match answer {
+            Some(answer) => return_if_else!(answer == THE_TRUTH, Ok(()), Err(EXPLAINING_WELL_KNOW)),
+            None => Err("No answer"),
+        }
+    }
+
+    #[test]
+    fn should_return_if_else_in_expression_position() {
+        assert_eq!(check_with_if_else_in_match(Some(42)), Ok(()));
+        assert_eq!(
+            check_with_if_else_in_match(Some(0)),
+            Err(EXPLAINING_WELL_KNOW)
+        );
+        assert_eq!(check_with_if_else_in_match(None), Err("No answer"));
+    }
+
+    fn parse_positive(text: &str) -> Result<u32, String> {
+        return_ok_if!(text.is_empty(), 1);
+        let number: i64 = text.parse().map_err(|_| format!("{text} is no number"))?;
+        return_err_if!(number <= 0, format!("{number} is not positive"));
+        Ok(number as u32)
+    }
+
+    #[test]
+    fn should_return_ok_if_true() {
+        assert_eq!(parse_positive(""), Ok(1));
+        assert_eq!(parse_positive("3"), Ok(3));
+    }
+
+    #[test]
+    fn should_return_err_if_true() {
+        assert_eq!(
+            parse_positive("-3"),
+            Err(String::from("-3 is not positive"))
+        );
+    }
+
+    fn parse_digit(text: Option<&str>) -> Result<u32, String> {
+        let digit: u32 = match text {
+            Some(text) => text.parse().map_err(|_| format!("{text} is no number"))?,
+            None => 0,
+        };
+        match text {
+            Some(_) => return_err_if!(digit > 9, format!("{digit} is no digit")),
+            None => return_ok_if!(digit == 0, digit),
+        }
+        Ok(digit)
+    }
+
+    #[test]
+    fn should_return_ok_or_err_if_true_in_expression_position() {
+        assert_eq!(parse_digit(None), Ok(0));
+        assert_eq!(parse_digit(Some("7")), Ok(7));
+        assert_eq!(parse_digit(Some("12")), Err(String::from("12 is no digit")));
+    }
+
+    #[test]
+    fn should_know_purpose_of_life() {
+        assert_eq!(test_if_you_know_purpose_of_life(42), Ok(()));
+        assert_eq!(
+            test_if_you_know_purpose_of_life(43),
+            Err(EXPLAINING_WELL_KNOW)
+        );
+    }
+
+    #[test]
+    fn should_accept_answer_within_tolerance() {
+        assert_eq!(test_with_tolerance(40, 2), Ok(()));
+        assert_eq!(test_with_tolerance(44, 2), Ok(()));
+    }
+
+    #[test]
+    fn should_reject_answer_outside_tolerance() {
+        assert_eq!(test_with_tolerance(39, 2), Err(EXPLAINING_WELL_KNOW));
+        assert_eq!(test_with_tolerance(41, 0), Err(EXPLAINING_WELL_KNOW));
+    }
+
+    fn describe_guess(guess: Option<u32>) -> &'static str {
+        match guess {
+            Some(guess) => return_if_within!(guess, 40..=44, "close"),
+            None => return "no guess",
+        }
+        "far away"
+    }
+
+    #[test]
+    fn should_return_if_within_in_expression_position() {
+        assert_eq!(describe_guess(Some(41)), "close");
+        assert_eq!(describe_guess(Some(7)), "far away");
+        assert_eq!(describe_guess(None), "no guess");
+    }
+
+    #[test]
+    fn should_break_if_true() {
+        let mut count = 0;
+        for _ in 0..100 {
+            break_if!(count == THE_TRUTH);
+            count += 1;
+        }
+        assert_eq!(count, THE_TRUTH);
+
+        let mut guess = 0;
+        let found = loop {
+            guess += 1;
+            break_if!(guess * 2 == THE_TRUTH => guess);
+        };
+        assert_eq!(found, 21);
+    }
+
+    #[test]
+    fn should_break_labeled_if_true() {
+        let mut pairs = Vec::new();
+        'outer: for a in 1..10 {
+            for b in 1..10 {
+                break_if!(a * b == THE_TRUTH, 'outer);
+                pairs.push((a, b));
+            }
+        }
+        assert_eq!(pairs.last(), Some(&(6, 6)));
+
+        let found = 'search: {
+            for a in 1..10 {
+                for b in 1..10 {
+                    break_if!(a * b == THE_TRUTH, 'search, (a, b));
+                }
+            }
+            (0, 0)
+        };
+        assert_eq!(found, (6, 7));
+    }
+
+    #[test]
+    fn should_continue_if_true() {
+        let mut odds = Vec::new();
+        for number in 1..8 {
+            continue_if!(number % 2 == 0);
+            odds.push(number);
+        }
+        assert_eq!(odds, vec![1, 3, 5, 7]);
+
+        let mut firsts = Vec::new();
+        'outer: for a in 1..4 {
+            for b in 1..4 {
+                continue_if!(b > 1, 'outer);
+                firsts.push((a, b));
+            }
+        }
+        assert_eq!(firsts, vec![(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn should_break_or_continue_if_true_in_expression_position() {
+        let mut evens = Vec::new();
+        for number in 1.. {
+            match number % 2 {
+                0 => break_if!(number > 6),
+                _ => continue_if!(true),
+            }
+            evens.push(number);
+        }
+        assert_eq!(evens, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn should_return_unless_equal() {
+        assert_eq!(explain_unless_truth(41), Err(EXPLAINING_WELL_KNOW));
+    }
+
+    #[test]
+    fn should_not_return_if_equal() {
+        assert_eq!(explain_unless_truth(42), Ok(()));
+    }
+
+    fn explain_unless_truth_in_match(answer: Option<u32>) -> Result<(), &'static str> {
+        match answer {
+            Some(answer) => return_unless!(answer, THE_TRUTH, Err(EXPLAINING_WELL_KNOW)),
+            None => return Err("No answer"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_unless_equal_in_expression_position() {
+        assert_eq!(explain_unless_truth_in_match(Some(42)), Ok(()));
+        assert_eq!(
+            explain_unless_truth_in_match(Some(41)),
+            Err(EXPLAINING_WELL_KNOW)
+        );
+        assert_eq!(explain_unless_truth_in_match(None), Err("No answer"));
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeSet;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use tree_of_madness::tree::Tree;
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+/// The tree is not balanced, sorted values turn it into a list and every insertion walks all
+/// nodes before. With 100_000 values this takes too long to be measured.
+const SORTED_SIZES: [usize; 2] = [1_000, 10_000];
+
+fn sorted_values(size: usize) -> Vec<i32> {
+    (0..size as i32).collect()
+}
+
+/// Same values as `sorted_values` in an order which is the same for every run.
+fn shuffled_values(size: usize) -> Vec<i32> {
+    let mut values = sorted_values(size);
+    let mut state: u64 = 42;
+
+    // Fisher-Yates shuffle with a xorshift generator.
+    for index in (1..values.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        values.swap(index, state as usize % (index + 1));
+    }
+
+    values
+}
+
+fn build_tree(values: &[i32]) -> Tree<i32> {
+    let mut tree = Tree::new();
+    for &value in values {
+        tree.add(value);
+    }
+    tree
+}
+
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert");
+    group.sample_size(10);
+
+    for (pattern, sizes, make_values) in [
+        (
+            "sorted",
+            &SORTED_SIZES[..],
+            sorted_values as fn(usize) -> Vec<i32>,
+        ),
+        ("shuffled", &SIZES[..], shuffled_values),
+    ] {
+        for &size in sizes {
+            let values = make_values(size);
+            group.bench_with_input(
+                BenchmarkId::new(format!("tree/{pattern}"), size),
+                &values,
+                |b, values| b.iter(|| build_tree(black_box(values))),
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("btree_set/{pattern}"), size),
+                &values,
+                |b, values| b.iter(|| black_box(values).iter().copied().collect::<BTreeSet<_>>()),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_contains(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contains");
+    group.sample_size(10);
+
+    for size in SIZES {
+        let values = shuffled_values(size);
+        let tree = build_tree(&values);
+        let set: BTreeSet<_> = values.iter().copied().collect();
+
+        group.bench_with_input(BenchmarkId::new("tree", size), &values, |b, values| {
+            b.iter(|| values.iter().filter(|value| tree.contains(value)).count())
+        });
+        group.bench_with_input(BenchmarkId::new("btree_set", size), &values, |b, values| {
+            b.iter(|| values.iter().filter(|value| set.contains(value)).count())
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_delete(c: &mut Criterion) {
+    let mut group = c.benchmark_group("delete");
+    group.sample_size(10);
+
+    for size in SIZES {
+        let values = shuffled_values(size);
+
+        group.bench_with_input(BenchmarkId::new("tree", size), &values, |b, values| {
+            b.iter_batched(
+                || build_tree(values),
+                |mut tree| {
+                    for value in values {
+                        tree.delete(value);
+                    }
+                    tree
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("btree_set", size), &values, |b, values| {
+            b.iter_batched(
+                || values.iter().copied().collect::<BTreeSet<_>>(),
+                |mut set| {
+                    for value in values {
+                        set.remove(value);
+                    }
+                    set
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_insert, bench_contains, bench_delete);
+criterion_main!(benches);
@@ -1,2 +1,10 @@
+//! Binary search tree with shared nodes.
+//! Without the default feature std, the crate is `no_std` and only needs `alloc` for its nodes.
+//! The feature std adds nodes, which can be shared between threads.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod node;
+pub mod node_ref;
 pub mod tree;
@@ -1,41 +1,38 @@
 //! Many operations on a node are implemented as associated function instead as methods.
-//! Reason: A node only gets exposed as a packed  Rc<RefCell<...>> construct to the user.
+//! Reason: A node only gets exposed as a packed shared pointer like Rc<RefCell<...>> to the user.
 //! The associated functions borrow of the inner node themself,
 //! This eases usage and reduce runtime violation via borrowing on RefCell,
-use std::{
-    cell::RefCell,
-    rc::{Rc, Weak},
-};
+use crate::node_ref::NodeRef;
+use crate::tree::TreeError;
 #[derive(Debug, Copy, Clone)]
 pub enum DiretionFromParent {
     Left,
     Right,
     NoParent,
 }
-type ParentNode<T> = Weak<RefCell<Node<T>>>;
-pub(crate) type RootNode<T> = Rc<RefCell<Node<T>>>;
-#[derive(Debug)]
-pub(crate) struct Node<T> {
-    parent: Option<ParentNode<T>>,
-    value: Rc<T>,
+type ParentNode<T, P> = <P as NodeRef>::Weak<Node<T, P>>;
+pub(crate) type RootNode<T, P> = <P as NodeRef>::Shared<Node<T, P>>;
+pub(crate) struct Node<T, P: NodeRef> {
+    parent: Option<ParentNode<T, P>>,
+    value: P::Value<T>,
     dir_to_parent: DiretionFromParent,
-    left: Option<RootNode<T>>,
-    right: Option<RootNode<T>>,
+    left: Option<RootNode<T, P>>,
+    right: Option<RootNode<T, P>>,
 }
 
-impl<T> Node<T> {
-    pub fn new(new_value: T) -> RootNode<T> {
-        Rc::new(RefCell::new(Node {
+impl<T, P: NodeRef> Node<T, P> {
+    pub fn new(new_value: T) -> RootNode<T, P> {
+        P::new(Node {
             parent: None,
             left: None,
             right: None,
             dir_to_parent: DiretionFromParent::NoParent,
-            value: Rc::new(new_value),
-        }))
+            value: P::new_value(new_value),
+        })
     }
 
-    pub fn get_shared_value(&self) -> Rc<T> {
-        Rc::clone(&self.value)
+    pub fn get_shared_value(&self) -> P::Value<T> {
+        self.value.clone()
     }
 
     pub fn get_direction_from_parent(&self) -> DiretionFromParent {
@@ -47,100 +44,97 @@ impl<T> Node<T> {
     }
 
     /// Returns left child as shared owned value.
-    pub fn get_left_child_shared(&self) -> Option<RootNode<T>> {
-        self.left.as_ref().map(|node| Rc::clone(node))
+    pub fn get_left_child_shared(&self) -> Option<RootNode<T, P>> {
+        self.left.clone()
     }
 
     /// Returns right child as shared owned value.
-    pub fn get_right_child_shared(&self) -> Option<RootNode<T>> {
-        self.right.as_ref().map(|node| Rc::clone(node))
+    pub fn get_right_child_shared(&self) -> Option<RootNode<T, P>> {
+        self.right.clone()
     }
 
     /// Creates a new node with the given value and then makes this new node
     /// the left child of the provided node.
-    pub fn spawn_left_child(parent: &RootNode<T>, left_value: T) {
-        let left_child = Node::new(left_value);
+    pub fn spawn_left_child(parent: &RootNode<T, P>, left_value: T) -> RootNode<T, P> {
+        let left_child = Self::new(left_value);
         {
-            parent.borrow_mut().left = Some(Rc::clone(&left_child));
+            P::borrow_mut(parent).left = Some(left_child.clone());
         }
 
         Self::set_parent(parent, &left_child, DiretionFromParent::Left);
+        left_child
     }
 
     /// Creates a new node with the given value and then makes this new node
     /// the right child of the provided node.
-    pub fn spawn_right_child(parent: &RootNode<T>, right_value: T) {
-        let right_child = Node::new(right_value);
+    pub fn spawn_right_child(parent: &RootNode<T, P>, right_value: T) -> RootNode<T, P> {
+        let right_child = Self::new(right_value);
         {
-            parent.borrow_mut().right = Some(right_child.clone());
+            P::borrow_mut(parent).right = Some(right_child.clone());
         }
 
         Self::set_parent(parent, &right_child, DiretionFromParent::Right);
+        right_child
     }
 
     /// Returns parent of node. It increments the reference counter to the undelying node.
     /// Returns None if the node has no parent.
     /// In this case the node the root usually.
-    pub fn get_parent(child: &RootNode<T>) -> Option<RootNode<T>> {
-        child
-            .borrow()
-            .parent
-            .as_ref()
-            .and_then(|parent| parent.upgrade())
+    pub fn get_parent(child: &RootNode<T, P>) -> Option<RootNode<T, P>> {
+        P::borrow(child).parent.as_ref().and_then(P::upgrade)
     }
 
-    pub fn take_child_from_parent(child: &RootNode<T>) {
-        let dir_from_parent = child.borrow().get_direction_from_parent();
-        let parent = Self::get_parent(&child);
+    /// Returns an error if the child has a direction from its parent but no parent.
+    pub fn take_child_from_parent(child: &RootNode<T, P>) -> Result<(), TreeError> {
+        let dir_from_parent = P::borrow(child).get_direction_from_parent();
+        let parent = Self::get_parent(child);
         match dir_from_parent {
             DiretionFromParent::NoParent => (),
             DiretionFromParent::Left => {
-                _ = Self::take_left_child(
-                    &parent.expect("With left as direction from parent, there must be a parent"),
-                );
+                _ = Self::take_left_child(&parent.ok_or(TreeError::MissingParent)?);
             }
             DiretionFromParent::Right => {
-                _ = Self::take_right_child(
-                    &parent.expect("With right as direction from parent, there must be a parent"),
-                );
+                _ = Self::take_right_child(&parent.ok_or(TreeError::MissingParent)?);
             }
         }
+
+        Ok(())
     }
 
-    fn set_parent(parent: &RootNode<T>, child: &RootNode<T>, dir: DiretionFromParent) {
-        let weak_to_parent = Some(Rc::downgrade(&parent));
+    fn set_parent(parent: &RootNode<T, P>, child: &RootNode<T, P>, dir: DiretionFromParent) {
+        let weak_to_parent = Some(P::downgrade(parent));
         {
-            let mut mut_child = child.borrow_mut();
+            let mut mut_child = P::borrow_mut(child);
             mut_child.parent = weak_to_parent;
             mut_child.dir_to_parent = dir;
         }
     }
 
-    fn unset_parent(child: &RootNode<T>) {
-        let mut child_mut = child.borrow_mut();
+    fn unset_parent(child: &RootNode<T, P>) {
+        let mut child_mut = P::borrow_mut(child);
         child_mut.parent = None;
         child_mut.dir_to_parent = DiretionFromParent::NoParent;
     }
 
     /// Removes left child on given node and returns this child as orphan, with no parent.
     /// If there is no child to be removed then None is returned.
-    pub fn take_left_child(parent: &RootNode<T>) -> Option<RootNode<T>> {
-        Self::take_child(&mut parent.borrow_mut().left)
+    pub fn take_left_child(parent: &RootNode<T, P>) -> Option<RootNode<T, P>> {
+        Self::take_child(&mut P::borrow_mut(parent).left)
     }
 
     /// Removes right child on given node and returns this child as orphan, with no parent.
     /// If there is no child to be removed then None is returned.
-    pub fn take_right_child(parent: &RootNode<T>) -> Option<RootNode<T>> {
-        Self::take_child(&mut parent.borrow_mut().right)
+    pub fn take_right_child(parent: &RootNode<T, P>) -> Option<RootNode<T, P>> {
+        Self::take_child(&mut P::borrow_mut(parent).right)
     }
 
     pub fn replace_left_child_with(
-        parent: &RootNode<T>,
-        new_left_child: RootNode<T>,
-    ) -> Option<RootNode<T>> {
-        Node::set_parent(parent, &new_left_child, DiretionFromParent::Left);
+        parent: &RootNode<T, P>,
+        new_left_child: RootNode<T, P>,
+    ) -> Option<RootNode<T, P>> {
+        Self::set_parent(parent, &new_left_child, DiretionFromParent::Left);
 
-        let old_left_child = parent.borrow_mut().left.replace(new_left_child);
+        let old_left_child = P::borrow_mut(parent).left.replace(new_left_child);
 
         if let Some(ref orphan) = old_left_child {
             Self::unset_parent(orphan);
@@ -150,12 +144,12 @@ impl<T> Node<T> {
     }
 
     pub fn replace_right_child_with(
-        parent: &RootNode<T>,
-        new_right_child: RootNode<T>,
-    ) -> Option<RootNode<T>> {
-        Node::set_parent(parent, &new_right_child, DiretionFromParent::Right);
+        parent: &RootNode<T, P>,
+        new_right_child: RootNode<T, P>,
+    ) -> Option<RootNode<T, P>> {
+        Self::set_parent(parent, &new_right_child, DiretionFromParent::Right);
 
-        let old_right_child = parent.borrow_mut().right.replace(new_right_child);
+        let old_right_child = P::borrow_mut(parent).right.replace(new_right_child);
 
         if let Some(ref orphan) = old_right_child {
             Self::unset_parent(orphan);
@@ -167,30 +161,29 @@ impl<T> Node<T> {
     /// Parent of parameter old_child replaces old_child with the parameter new_child as respective
     /// child.
     /// Returns the parent of the old_child. Returns None if the old_child has no parent.
+    /// Returns an error if old_child has a parent but no direction from it.
     ///
     /// Example: if old_child is the left child of another node, parent, then the new_child
     /// will become the new left child of the parent.
     pub fn let_parent_replace_child_with(
-        old_child: RootNode<T>,
-        new_child: RootNode<T>,
-    ) -> Option<RootNode<T>> {
+        old_child: RootNode<T, P>,
+        new_child: RootNode<T, P>,
+    ) -> Result<Option<RootNode<T, P>>, TreeError> {
         if let Some(parent) = Self::get_parent(&old_child) {
-            let direction = old_child.borrow().get_direction_from_parent();
+            let direction = P::borrow(&old_child).get_direction_from_parent();
             match direction {
                 DiretionFromParent::Left => Self::replace_left_child_with(&parent, new_child),
                 DiretionFromParent::Right => Self::replace_right_child_with(&parent, new_child),
-                DiretionFromParent::NoParent => panic!(
-                    "At this point, there must be a parent. The direction is missing to connect the child to the parent"
-                    ),
+                DiretionFromParent::NoParent => return Err(TreeError::MissingDirection),
             };
 
-            Some(parent)
+            Ok(Some(parent))
         } else {
-            None
+            Ok(None)
         }
     }
 
-    fn take_child(child_to_take: &mut Option<RootNode<T>>) -> Option<RootNode<T>> {
+    fn take_child(child_to_take: &mut Option<RootNode<T, P>>) -> Option<RootNode<T, P>> {
         if let Some(orphan) = child_to_take.take() {
             {
                 Self::unset_parent(&orphan);
@@ -203,12 +196,12 @@ impl<T> Node<T> {
 
     /// Returns the node with the largest value from the parameter to_search_from as root.
     /// Returns none if the to_search_from has no children.
-    pub fn find_greatest_node_from(to_search_from: &RootNode<T>) -> Option<RootNode<T>> {
+    pub fn find_greatest_node_from(to_search_from: &RootNode<T, P>) -> Option<RootNode<T, P>> {
         let mut previous_node = None;
-        let mut current_largest = to_search_from.borrow().get_right_child_shared();
+        let mut current_largest = P::borrow(to_search_from).get_right_child_shared();
         while let Some(next_right_node) = current_largest {
-            previous_node = Some(Rc::clone(&next_right_node));
-            current_largest = next_right_node.borrow().get_right_child_shared();
+            previous_node = Some(next_right_node.clone());
+            current_largest = P::borrow(&next_right_node).get_right_child_shared();
         }
 
         previous_node
@@ -217,16 +210,20 @@ impl<T> Node<T> {
     /// Searches the node with largest node from the parameter to_search_from as root.
     /// Then if any
     /// Returns none if the parameter to_search_from has no right children
-    pub fn extract_greatest_node_from(to_search_from: &RootNode<T>) -> Option<RootNode<T>> {
-        let largest_node = Self::find_greatest_node_from(to_search_from)?;
+    pub fn extract_greatest_node_from(
+        to_search_from: &RootNode<T, P>,
+    ) -> Result<Option<RootNode<T, P>>, TreeError> {
+        let Some(largest_node) = Self::find_greatest_node_from(to_search_from) else {
+            return Ok(None);
+        };
 
         if let Some(left_child_largest) = Self::take_left_child(&largest_node) {
-            _ = Self::let_parent_replace_child_with(Rc::clone(&largest_node), left_child_largest);
+            _ = Self::let_parent_replace_child_with(largest_node.clone(), left_child_largest)?;
         } else {
-            _ = Self::take_child_from_parent(&largest_node);
+            Self::take_child_from_parent(&largest_node)?;
         }
 
-        Some(largest_node)
+        Ok(Some(largest_node))
     }
 
     pub fn left_right_taken(&self) -> (bool, bool) {
@@ -237,7 +234,10 @@ impl<T> Node<T> {
 #[cfg(test)]
 mod testing {
 
-    use super::Node;
+    use crate::node_ref::RcRefCell;
+    use crate::tree::TreeError;
+
+    type Node<T> = super::Node<T, RcRefCell>;
 
     #[test]
     fn should_left_add() {
@@ -253,9 +253,8 @@ mod testing {
             None => panic!("No left child created"),
         }
 
-        match actual_right_child {
-            Some(_) => panic!("Should not add left value as right child"),
-            None => (),
+        if actual_right_child.is_some() {
+            panic!("Should not add left value as right child")
         }
     }
 
@@ -272,12 +271,24 @@ mod testing {
             Some(child) => assert_eq!(&expected_value, child.borrow().get_value_ref()),
             None => panic!("No right child created"),
         }
-        match actual_left_child {
-            Some(_) => panic!("Should not add right value as left child"),
-            None => (),
+        if actual_left_child.is_some() {
+            panic!("Should not add right value as left child")
         }
     }
 
+    #[test]
+    fn should_fail_to_take_child_from_dropped_parent() {
+        let parent = Node::new(2u32);
+        Node::spawn_left_child(&parent, 1);
+        let child = parent.borrow().get_left_child_shared().unwrap();
+        drop(parent);
+
+        assert_eq!(
+            Node::take_child_from_parent(&child),
+            Err(TreeError::MissingParent)
+        );
+    }
+
     #[test]
     fn should_add_and_remove_left() {
         let root = Node::new(2u32);
@@ -291,9 +302,8 @@ mod testing {
         }
 
         let borrowed_root = root.borrow();
-        match borrowed_root.get_left_child_shared() {
-            Some(_) => panic!("Left child was not removed."),
-            None => (),
+        if borrowed_root.get_left_child_shared().is_some() {
+            panic!("Left child was not removed.")
         }
     }
     #[test]
@@ -309,9 +319,8 @@ mod testing {
         }
 
         let borrowed_root = root.borrow();
-        match borrowed_root.get_left_child_shared() {
-            Some(_) => panic!("Right child was not removed."),
-            None => (),
+        if borrowed_root.get_left_child_shared().is_some() {
+            panic!("Right child was not removed.")
         }
     }
 }
@@ -0,0 +1,186 @@
+//! The shared pointers which link the nodes of a tree.
+//! A tree is generic over [`NodeRef`], so the same code builds a tree with `Rc<RefCell<_>>`
+//! for one thread via [`RcRefCell`] or with `Arc<RwLock<_>>` for many threads via `ArcRwLock`.
+use alloc::rc::{self, Rc};
+use core::cell::{Ref, RefCell, RefMut};
+use core::ops::{Deref, DerefMut};
+
+/// Operations on a shared and mutable pointer, which a tree needs for its nodes.
+/// The implementing type is only a marker, which picks the pointer kind.
+pub trait NodeRef: Sized {
+    /// Owning pointer to a node.
+    type Shared<X>: Clone;
+    /// Pointer from a child to its parent, which does not keep the parent alive.
+    type Weak<X>;
+    /// Pointer to a value in the tree, which is handed out by the iterators.
+    type Value<T>: Deref<Target = T> + Clone;
+    /// Reading access to a node.
+    type Ref<'a, X: 'a>: Deref<Target = X>;
+    /// Writing access to a node.
+    type RefMut<'a, X: 'a>: DerefMut<Target = X>;
+
+    fn new<X>(node: X) -> Self::Shared<X>;
+    fn new_value<T>(value: T) -> Self::Value<T>;
+    fn borrow<'a, X: 'a>(shared: &'a Self::Shared<X>) -> Self::Ref<'a, X>;
+    fn borrow_mut<'a, X: 'a>(shared: &'a Self::Shared<X>) -> Self::RefMut<'a, X>;
+    fn downgrade<X>(shared: &Self::Shared<X>) -> Self::Weak<X>;
+    fn upgrade<X>(weak: &Self::Weak<X>) -> Option<Self::Shared<X>>;
+}
+
+/// Nodes as `Rc<RefCell<_>>` and values as `Rc<T>`. Default of a tree.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RcRefCell;
+
+impl NodeRef for RcRefCell {
+    type Shared<X> = Rc<RefCell<X>>;
+    type Weak<X> = rc::Weak<RefCell<X>>;
+    type Value<T> = Rc<T>;
+    type Ref<'a, X: 'a> = Ref<'a, X>;
+    type RefMut<'a, X: 'a> = RefMut<'a, X>;
+
+    fn new<X>(node: X) -> Self::Shared<X> {
+        Rc::new(RefCell::new(node))
+    }
+
+    fn new_value<T>(value: T) -> Self::Value<T> {
+        Rc::new(value)
+    }
+
+    fn borrow<'a, X: 'a>(shared: &'a Self::Shared<X>) -> Self::Ref<'a, X> {
+        shared.borrow()
+    }
+
+    fn borrow_mut<'a, X: 'a>(shared: &'a Self::Shared<X>) -> Self::RefMut<'a, X> {
+        shared.borrow_mut()
+    }
+
+    fn downgrade<X>(shared: &Self::Shared<X>) -> Self::Weak<X> {
+        Rc::downgrade(shared)
+    }
+
+    fn upgrade<X>(weak: &Self::Weak<X>) -> Option<Self::Shared<X>> {
+        weak.upgrade()
+    }
+}
+
+#[cfg(feature = "std")]
+pub use sync::ArcRwLock;
+
+#[cfg(feature = "std")]
+mod sync {
+    use super::NodeRef;
+    use std::sync::{self, Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    /// Nodes as `Arc<RwLock<_>>` and values as `Arc<T>`, so a tree can be sent to and shared
+    /// between threads. Needs the feature std.
+    ///
+    /// A lock poisoned by a panic is used anyway like a `RefCell` would be.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct ArcRwLock;
+
+    impl NodeRef for ArcRwLock {
+        type Shared<X> = Arc<RwLock<X>>;
+        type Weak<X> = sync::Weak<RwLock<X>>;
+        type Value<T> = Arc<T>;
+        type Ref<'a, X: 'a> = RwLockReadGuard<'a, X>;
+        type RefMut<'a, X: 'a> = RwLockWriteGuard<'a, X>;
+
+        fn new<X>(node: X) -> Self::Shared<X> {
+            Arc::new(RwLock::new(node))
+        }
+
+        fn new_value<T>(value: T) -> Self::Value<T> {
+            Arc::new(value)
+        }
+
+        fn borrow<'a, X: 'a>(shared: &'a Self::Shared<X>) -> Self::Ref<'a, X> {
+            shared.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        fn borrow_mut<'a, X: 'a>(shared: &'a Self::Shared<X>) -> Self::RefMut<'a, X> {
+            shared.write().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        fn downgrade<X>(shared: &Self::Shared<X>) -> Self::Weak<X> {
+            Arc::downgrade(shared)
+        }
+
+        fn upgrade<X>(weak: &Self::Weak<X>) -> Option<Self::Shared<X>> {
+            weak.upgrade()
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::tree::Tree;
+    use alloc::vec::Vec;
+
+    fn in_order<P: NodeRef>(tree: &Tree<i32, P>) -> Vec<i32> {
+        tree.iter_in_order().map(|value| *value).collect()
+    }
+
+    /// Same operations for every kind of pointer.
+    fn run_operation_suite<P: NodeRef>(mut tree: Tree<i32, P>) {
+        //      35
+        //   10    46
+        //  4     38
+        //   8
+        for value in [35, 10, 4, 8, 46, 38] {
+            assert!(tree.add(value));
+        }
+        assert!(!tree.add(10));
+        assert!(tree.contains(&8));
+        assert!(!tree.contains(&9));
+
+        assert!(tree.delete(&10));
+        assert!(tree.delete(&35));
+        assert!(!tree.delete(&35));
+
+        assert_eq!(in_order(&tree), [4, 8, 38, 46]);
+        let breadth_first: Vec<_> = tree.iter_shared().map(|value| *value).collect();
+        assert_eq!(breadth_first, [8, 4, 46, 38]);
+        assert_eq!(tree.len(), 4);
+
+        for value in [4, 8, 38, 46] {
+            assert!(tree.delete(&value));
+        }
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn should_run_operations_with_rc_ref_cell() {
+        run_operation_suite(Tree::with_node_ref(RcRefCell));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_run_operations_with_arc_rw_lock() {
+        run_operation_suite(Tree::with_node_ref(ArcRwLock));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_share_tree_with_arc_rw_lock_between_threads() {
+        use std::thread;
+
+        let mut tree = Tree::with_node_ref(ArcRwLock);
+        for value in [5, 1, 9, 7, 3] {
+            tree.add(value);
+        }
+
+        let tree = thread::spawn(move || {
+            tree.delete(&5);
+            tree
+        })
+        .join()
+        .unwrap();
+
+        thread::scope(|scope| {
+            let sum = scope.spawn(|| tree.iter_shared().map(|value| *value).sum::<i32>());
+            assert_eq!(in_order(&tree), [1, 3, 7, 9]);
+            assert_eq!(sum.join().unwrap(), 20);
+        });
+    }
+}
@@ -1,61 +1,153 @@
 pub mod iteration;
+pub mod stats;
 
 use crate::node::{DiretionFromParent, Node, RootNode};
-use std::cmp::Ordering;
-use std::fmt::Debug;
-use std::rc::Rc;
+use crate::node_ref::{NodeRef, RcRefCell};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display};
+
+/// Broken links between the nodes of a tree.
+/// These can only occur if an invariant of the tree is violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError {
+    /// A node has a parent but no side (left, right) on the parent.
+    MissingDirection,
+    /// A node is the left or right child but its parent is gone.
+    MissingParent,
+    /// A child was expected at a node but there is none.
+    MissingChild,
+}
 
-#[derive(Debug)]
-pub struct Tree<T> {
-    root: Option<RootNode<T>>,
+impl Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::MissingDirection => {
+                write!(f, "missing side(left, right) between node and parent")
+            }
+            TreeError::MissingParent => write!(f, "missing parent of a left or right child"),
+            TreeError::MissingChild => write!(f, "missing child of a node"),
+        }
+    }
 }
-enum SearchResult<T> {
+
+impl core::error::Error for TreeError {}
+
+/// The nodes are linked via the pointers of `P`, see [`NodeRef`].
+/// Without `P` they are linked via `Rc<RefCell<_>>` for one thread.
+pub struct Tree<T, P: NodeRef = RcRefCell> {
+    root: Option<RootNode<T, P>>,
+}
+enum SearchResult<T, P: NodeRef> {
     TreeEmpty,
-    Found(RootNode<T>),
-    ClosestToValue(RootNode<T>, DiretionFromParent),
+    Found(RootNode<T, P>),
+    ClosestToValue(RootNode<T, P>, DiretionFromParent),
 }
 
 #[macro_export]
 macro_rules! build_tree {
     ($($v:expr),*) => {{
-        let mut _tree = Tree::new();
+        let mut _tree = $crate::tree::Tree::new();
         $(_tree.add($v);)*
         _tree
     }};
 }
 
+/// Shows the values from the smallest to the largest.
+impl<T: Debug, P: NodeRef> Debug for Tree<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut values = f.debug_set();
+        for value in self.iter_in_order() {
+            values.entry(&*value);
+        }
+        values.finish()
+    }
+}
+
+impl<T: Ord, P: NodeRef> Default for Tree<T, P> {
+    fn default() -> Self {
+        Tree { root: None }
+    }
+}
+
 impl<T: Ord> Tree<T> {
     pub fn new() -> Self {
         Tree { root: None }
     }
+}
 
-    fn find_value_from(root: &Option<RootNode<T>>, wanted_value: &T) -> SearchResult<T> {
+/// Adds the values one by one in their order, duplicates are kept only once.
+/// The tree is not balanced, so sorted values lead to a tree as deep as it has values.
+/// ```
+/// use tree_of_madness::tree::Tree;
+///
+/// let tree = Tree::from(vec![5, 3, 8, 3]);
+///
+/// assert_eq!(tree.len(), 3);
+/// ```
+impl<T: Ord> From<Vec<T>> for Tree<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut tree = Tree::new();
+        for value in values {
+            tree.add(value);
+        }
+        tree
+    }
+}
+
+/// Same as for a Vec, but clones the values.
+impl<T: Ord + Clone> From<&[T]> for Tree<T> {
+    fn from(values: &[T]) -> Self {
+        let mut tree = Tree::new();
+        for value in values {
+            tree.add(value.clone());
+        }
+        tree
+    }
+}
+
+impl<T: Ord, P: NodeRef> Tree<T, P> {
+    /// Empty tree whose nodes are linked via the pointers of the given kind.
+    /// ```
+    /// use tree_of_madness::node_ref::RcRefCell;
+    /// use tree_of_madness::tree::Tree;
+    ///
+    /// let mut tree = Tree::with_node_ref(RcRefCell);
+    /// tree.add(4);
+    ///
+    /// assert!(tree.contains(&4));
+    /// ```
+    pub fn with_node_ref(_node_ref: P) -> Self {
+        Tree { root: None }
+    }
+
+    fn find_value_from(root: &Option<RootNode<T, P>>, wanted_value: &T) -> SearchResult<T, P> {
         if let Some(root) = root.as_ref() {
-            let mut current_node = Rc::clone(root);
+            let mut current_node = root.clone();
             loop {
-                let ordering = wanted_value.cmp(current_node.borrow().get_value_ref());
+                let ordering = wanted_value.cmp(P::borrow(&current_node).get_value_ref());
                 match ordering {
                     Ordering::Equal => {
-                        return SearchResult::Found(Rc::clone(&current_node));
+                        return SearchResult::Found(current_node.clone());
                     }
                     Ordering::Less => {
-                        let left_child = current_node.borrow().get_left_child_shared();
+                        let left_child = P::borrow(&current_node).get_left_child_shared();
                         if let Some(new_current_node_child) = left_child {
                             current_node = new_current_node_child;
                         } else {
                             return SearchResult::ClosestToValue(
-                                Rc::clone(&current_node),
+                                current_node.clone(),
                                 DiretionFromParent::Left,
                             );
                         }
                     }
                     Ordering::Greater => {
-                        let right_child = current_node.borrow().get_right_child_shared();
+                        let right_child = P::borrow(&current_node).get_right_child_shared();
                         if let Some(new_right_child) = right_child {
                             current_node = new_right_child;
                         } else {
                             return SearchResult::ClosestToValue(
-                                Rc::clone(&current_node),
+                                current_node.clone(),
                                 DiretionFromParent::Right,
                             );
                         }
@@ -63,33 +155,76 @@ impl<T: Ord> Tree<T> {
                 }
             }
         } else {
-            return SearchResult::TreeEmpty;
+            SearchResult::TreeEmpty
         }
     }
 
+    /// Returns true if the value was added and false if it was already in the tree.
+    ///
+    /// # Panics
+    /// If the tree is broken. See [`Tree::try_add`].
     pub fn add(&mut self, new_value: T) -> bool {
+        self.try_add(new_value)
+            .unwrap_or_else(|error| panic!("Can not add value to tree.\nReason: {error}"))
+    }
+
+    /// Like [`Tree::add`] but returns an error instead of panicking if the tree is broken.
+    pub fn try_add(&mut self, new_value: T) -> Result<bool, TreeError> {
+        self.find_or_insert(new_value).map(|(_, added)| added)
+    }
+
+    /// Returns the node with a value equal to the given one.
+    /// If there is none, a new node with the given value is added and returned.
+    /// The flag is true if the node was added.
+    fn find_or_insert(&mut self, new_value: T) -> Result<(RootNode<T, P>, bool), TreeError> {
         match Self::find_value_from(&self.root, &new_value) {
             SearchResult::TreeEmpty => {
-                self.root = Some(Node::new(new_value));
-                true
+                let root = self.root.insert(Node::<T, P>::new(new_value));
+                Ok((root.clone(), true))
             }
-            SearchResult::Found(_) => false,
+            SearchResult::Found(existing) => Ok((existing, false)),
             SearchResult::ClosestToValue(attach_to, direction) => {
-                match direction {
-                    DiretionFromParent::Left => Node::spawn_left_child(&attach_to, new_value),
-                    DiretionFromParent::Right => Node::spawn_right_child(&attach_to, new_value),
-                    DiretionFromParent::NoParent => panic!(
-                        "{}\n{}",
-                        "Can not add value to tree.",
-                        "Reason: missing side(left, right) where to insert new value."
-                    ),
+                let added = match direction {
+                    DiretionFromParent::Left => {
+                        Node::<T, P>::spawn_left_child(&attach_to, new_value)
+                    }
+                    DiretionFromParent::Right => {
+                        Node::<T, P>::spawn_right_child(&attach_to, new_value)
+                    }
+                    DiretionFromParent::NoParent => return Err(TreeError::MissingDirection),
                 };
 
-                true
+                Ok((added, true))
             }
         }
     }
 
+    /// Returns the value in the tree, which is equal to the given one.
+    /// If there is none, the given value is added and returned.
+    /// Equal values share one allocation, so this can be used to intern values.
+    ///
+    /// # Panics
+    /// If the tree is broken. See [`Tree::try_add`].
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use tree_of_madness::tree::Tree;
+    ///
+    /// let mut names = Tree::new();
+    /// let first = names.get_or_insert(String::from("Ada"));
+    /// let second = names.get_or_insert(String::from("Ada"));
+    ///
+    /// assert!(Rc::ptr_eq(&first, &second));
+    /// ```
+    pub fn get_or_insert(&mut self, value: T) -> P::Value<T> {
+        let (node, _) = self
+            .find_or_insert(value)
+            .unwrap_or_else(|error| panic!("Can not add value to tree.\nReason: {error}"));
+
+        let shared_value = P::borrow(&node).get_shared_value();
+        shared_value
+    }
+
     /// Returns true if given value is in the tree, otherwiese returns false.
     /// # Example
     /// ```
@@ -107,82 +242,136 @@ impl<T: Ord> Tree<T> {
         }
     }
 
+    /// Returns true if the value was deleted and false if it was not in the tree.
+    ///
+    /// # Panics
+    /// If the tree is broken. See [`Tree::try_delete`].
     pub fn delete(&mut self, to_delete: &T) -> bool
+    where
+        T: Ord + Debug,
+    {
+        self.try_delete(to_delete)
+            .unwrap_or_else(|error| panic!("Can not delete value from tree.\nReason: {error}"))
+    }
+
+    /// Deletes every given value and returns how many of them were in the tree.
+    ///
+    /// # Panics
+    /// If the tree is broken. See [`Tree::try_delete`].
+    /// # Example
+    /// ```
+    /// use tree_of_madness::build_tree;
+    ///
+    /// let mut tree = build_tree![10, 3, 16];
+    ///
+    /// assert_eq!(tree.remove_all([3, 16, 99]), 2);
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn remove_all<I>(&mut self, values: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+        T: Ord + Debug,
+    {
+        values
+            .into_iter()
+            .filter(|to_delete| self.delete(to_delete))
+            .count()
+    }
+
+    /// Like [`Tree::delete`] but returns an error instead of panicking if the tree is broken.
+    pub fn try_delete(&mut self, to_delete: &T) -> Result<bool, TreeError>
     where
         T: Ord + Debug,
     {
         return match Self::find_value_from(&self.root, to_delete) {
-            SearchResult::TreeEmpty | SearchResult::ClosestToValue(..) => false,
+            SearchResult::TreeEmpty | SearchResult::ClosestToValue(..) => Ok(false),
             SearchResult::Found(gone_with_it) => {
-                let left_right = gone_with_it.borrow().left_right_taken();
+                let left_right = P::borrow(&gone_with_it).left_right_taken();
+                let direction = P::borrow(&gone_with_it).get_direction_from_parent();
                 match left_right {
-                    (false, false) => Node::take_child_from_parent(&gone_with_it),
+                    (false, false) => match direction {
+                        // A leaf without parent is the only node of the tree.
+                        DiretionFromParent::NoParent => self.root = None,
+                        _ => Node::<T, P>::take_child_from_parent(&gone_with_it)?,
+                    },
                     (false, true) => {
-                        let new_right_child = Node::take_right_child(&gone_with_it)
-                            .expect("Here it is known that there is a right child.");
+                        let new_right_child = Node::<T, P>::take_right_child(&gone_with_it)
+                            .ok_or(TreeError::MissingChild)?;
 
-                        let_parent_or_root_replace_child_with(self, gone_with_it, new_right_child);
+                        let_parent_or_root_replace_child_with(self, gone_with_it, new_right_child)?;
                     }
                     (true, false) => {
-                        let new_left_child = Node::take_left_child(&gone_with_it)
-                            .expect("Here it is known that there is a left child.");
+                        let new_left_child = Node::<T, P>::take_left_child(&gone_with_it)
+                            .ok_or(TreeError::MissingChild)?;
 
-                        let_parent_or_root_replace_child_with(self, gone_with_it, new_left_child);
+                        let_parent_or_root_replace_child_with(self, gone_with_it, new_left_child)?;
                     }
                     (true, true) => {
-                        let left_detached = Node::take_left_child(&gone_with_it)
-                            .expect("Should have a left child at this point");
-                        let right_detached = Node::take_right_child(&gone_with_it)
-                            .expect("Should have a right child at this point");
+                        let left_detached = Node::<T, P>::take_left_child(&gone_with_it)
+                            .ok_or(TreeError::MissingChild)?;
+                        let right_detached = Node::<T, P>::take_right_child(&gone_with_it)
+                            .ok_or(TreeError::MissingChild)?;
 
-                        match Node::extract_greatest_node_from(&left_detached) {
+                        match Node::<T, P>::extract_greatest_node_from(&left_detached)? {
                             Some(largest_node) => {
-                                Node::replace_left_child_with(&largest_node, left_detached);
-                                Node::replace_right_child_with(&largest_node, right_detached);
+                                Node::<T, P>::replace_left_child_with(&largest_node, left_detached);
+                                Node::<T, P>::replace_right_child_with(
+                                    &largest_node,
+                                    right_detached,
+                                );
                                 let_parent_or_root_replace_child_with(
                                     self,
                                     gone_with_it,
                                     largest_node,
-                                );
+                                )?;
                             }
                             None => {
+                                // Without a right child, the left child is the largest node of its
+                                // subtree and keeps its own left children.
                                 let largest_node = left_detached;
-                                Node::replace_left_child_with(&largest_node, right_detached);
+                                Node::<T, P>::replace_right_child_with(
+                                    &largest_node,
+                                    right_detached,
+                                );
                                 let_parent_or_root_replace_child_with(
                                     self,
                                     gone_with_it,
                                     largest_node,
-                                );
+                                )?;
                             }
                         };
                     }
                 };
 
-                true
+                Ok(true)
             }
         };
 
-        fn let_parent_or_root_replace_child_with<T>(
-            tree: &mut Tree<T>,
-            gone_with_it: RootNode<T>,
-            new_child: RootNode<T>,
-        ) {
+        fn let_parent_or_root_replace_child_with<T, P: NodeRef>(
+            tree: &mut Tree<T, P>,
+            gone_with_it: RootNode<T, P>,
+            new_child: RootNode<T, P>,
+        ) -> Result<(), TreeError> {
             let changed_parent =
-                Node::let_parent_replace_child_with(gone_with_it, Rc::clone(&new_child));
+                Node::<T, P>::let_parent_replace_child_with(gone_with_it, new_child.clone())?;
             // There is no parent for the child of the delteted node. In this case the deleted node is the
             // root of the tree.
-            if let None = changed_parent {
+            if changed_parent.is_none() {
                 tree.root = Some(new_child);
             }
+
+            Ok(())
         }
     }
 }
+#[cfg(test)]
+use alloc::rc::Rc;
+
 #[cfg(test)]
 impl<T: Ord> Tree<T> {
-    fn get_root_node(&self) -> RootNode<T> {
+    fn get_root_node(&self) -> RootNode<T, RcRefCell> {
         Rc::clone(
-            &self
-                .root
+            self.root
                 .as_ref()
                 .expect("No root found to return for test."),
         )
@@ -208,6 +397,175 @@ mod testing {
         assert!(!tree.contains(&0));
     }
 
+    #[test]
+    fn should_add_and_delete_with_ok() {
+        let mut tree = Tree::new();
+
+        assert_eq!(tree.try_add(10), Ok(true));
+        assert_eq!(tree.try_add(5), Ok(true));
+        assert_eq!(tree.try_add(5), Ok(false));
+        assert_eq!(tree.try_delete(&5), Ok(true));
+        assert_eq!(tree.try_delete(&5), Ok(false));
+    }
+
+    #[test]
+    fn should_return_error_for_broken_tree() {
+        // The left child of a dropped tree still knows it is a left child, but its parent is gone.
+        let left_child = build_tree![10, 5]
+            .get_root_node()
+            .borrow()
+            .get_left_child_shared()
+            .unwrap();
+        let mut broken: Tree<_> = Tree {
+            root: Some(left_child),
+        };
+
+        assert!(broken.contains(&5));
+        assert_eq!(broken.try_delete(&5), Err(TreeError::MissingParent));
+    }
+
+    #[test]
+    fn should_delete_only_root() {
+        let mut tree = build_tree![19];
+
+        assert!(tree.delete(&19));
+
+        assert!(tree.is_empty());
+        assert!(!tree.contains(&19));
+    }
+
+    #[test]
+    fn should_return_stats_of_unbalanced_tree() {
+        //      35
+        //   10
+        //  4  16
+        //   8
+        //  6
+        let stats = build_tree![35, 10, 4, 8, 6, 16].stats();
+
+        assert_eq!(
+            stats,
+            stats::TreeStats {
+                len: 6,
+                height: 5,
+                leaves: 2,
+                is_balanced: false,
+            }
+        );
+    }
+
+    #[test]
+    fn should_return_stats_of_empty_and_balanced_trees() {
+        let empty = Tree::<i32>::new().stats();
+        let balanced = build_tree![8, 4, 12, 2, 6, 10, 14, 1].stats();
+
+        assert_eq!((empty.len, empty.height, empty.leaves), (0, 0, 0));
+        assert!(empty.is_balanced);
+        assert_eq!((balanced.len, balanced.height, balanced.leaves), (8, 4, 4));
+        assert!(balanced.is_balanced);
+        assert!(!build_tree![1, 2, 3].stats().is_balanced);
+    }
+
+    #[test]
+    fn should_return_same_allocation_for_equal_values() {
+        let mut tree = build_tree![35, 10, 46];
+
+        let first = tree.get_or_insert(16);
+        let second = tree.get_or_insert(16);
+        let existing = tree.get_or_insert(35);
+
+        assert!(Rc::ptr_eq(&first, &second));
+        assert!(Rc::ptr_eq(
+            &existing,
+            &tree.get_root_node().borrow().get_shared_value()
+        ));
+        assert_eq!((*first, *existing), (16, 35));
+        assert_eq!(tree.len(), 4);
+    }
+
+    #[test]
+    fn should_insert_into_empty_tree_on_get() {
+        let mut tree = Tree::new();
+
+        let root = tree.get_or_insert(7);
+
+        assert!(Rc::ptr_eq(&root, &tree.get_or_insert(7)));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn should_remove_all_given_values_in_tree() {
+        let mut tree = build_tree![35, 10, 4, 8, 6, 16];
+
+        assert_eq!(tree.remove_all([4, 16, 99]), 2);
+
+        let survivors: Vec<_> = tree.iter_in_order().map(|value| *value).collect();
+        assert_eq!(survivors, [6, 8, 10, 35]);
+        assert_eq!(tree.remove_all(Vec::new()), 0);
+        assert_eq!(tree.remove_all([35, 35, 6, 8, 10]), 4);
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn should_take_values_from_both_ends() {
+        let tree = build_tree![5, 1, 9, 7, 3];
+        let values = |taken: Vec<Rc<i32>>| taken.iter().map(|value| **value).collect::<Vec<_>>();
+
+        assert_eq!(values(tree.take_largest(2)), [9, 7]);
+        assert_eq!(values(tree.take_smallest(2)), [1, 3]);
+        assert_eq!(values(tree.take_largest(10)), [9, 7, 5, 3, 1]);
+        assert!(tree.take_smallest(0).is_empty());
+        assert!(Tree::<i32>::new().take_largest(3).is_empty());
+    }
+
+    #[test]
+    fn should_build_from_vec_without_duplicates() {
+        let tree = Tree::from(vec![5, 3, 8, 3, 5]);
+
+        let values: Vec<_> = tree.iter_shared().map(|value| *value).collect();
+        assert_eq!(values, [5, 3, 8]);
+    }
+
+    #[test]
+    fn should_build_from_slice_without_duplicates() {
+        let words = [String::from("b"), String::from("a"), String::from("b")];
+
+        let tree = Tree::from(&words[..]);
+
+        let values: Vec<_> = tree.iter_in_order().map(|value| (*value).clone()).collect();
+        assert_eq!(values, ["a", "b"]);
+        assert_eq!(words.len(), 3);
+    }
+
+    #[test]
+    fn should_iterate_level_by_level_in_same_order_for_same_shape() {
+        let level_order =
+            |tree: Tree<i32>| tree.iter_shared().map(|value| *value).collect::<Vec<_>>();
+
+        // Another order of adding which leads to the same shape gives the same sequence.
+        assert_eq!(
+            level_order(build_tree![35, 10, 46, 4, 38, 8]),
+            level_order(build_tree![35, 46, 10, 38, 4, 8])
+        );
+        assert_eq!(
+            level_order(build_tree![35, 10, 46, 4, 38, 8, 8, 35]),
+            [35, 10, 46, 4, 38, 8]
+        );
+    }
+
+    #[test]
+    fn should_keep_order_after_deleting_node_with_two_children() {
+        //    -16
+        // -17    0
+        let mut tree = build_tree![-16, -17, 0];
+
+        assert!(tree.delete(&-16));
+
+        let in_order: Vec<_> = tree.iter_in_order().map(|value| *value).collect();
+        assert_eq!(in_order, vec![-17, 0]);
+        assert_eq!(tree.len(), 2);
+    }
+
     #[test]
     fn should_delete_leafs() {
         //      35
@@ -303,7 +661,7 @@ mod testing {
         let tree = build_tree![100];
         let root = tree.get_root_node();
 
-        let actual_node_found = Node::find_greatest_node_from(&root);
+        let actual_node_found = Node::<_, RcRefCell>::find_greatest_node_from(&root);
 
         assert!(actual_node_found.is_none());
     }
@@ -318,8 +676,8 @@ mod testing {
         assert_greatest_node_subtree(&root.borrow().get_right_child_shared().unwrap(), 400);
     }
 
-    fn assert_greatest_node_subtree(subroot: &RootNode<i32>, expected_value: i32) {
-        let actual_node_found = Node::find_greatest_node_from(&subroot)
+    fn assert_greatest_node_subtree(subroot: &RootNode<i32, RcRefCell>, expected_value: i32) {
+        let actual_node_found = Node::<_, RcRefCell>::find_greatest_node_from(subroot)
             .expect("No greatest node from left was returned.");
 
         assert_eq!(&expected_value, actual_node_found.borrow().get_value_ref());
@@ -350,7 +708,7 @@ mod testing {
     fn asssert_deletion_with_children(mut tree: Tree<i32>, to_delete: i32, expected: &[i32]) {
         let has_deleted = tree.delete(&to_delete);
         let actual: Vec<_> = tree.iter_shared().collect();
-        let expected_rc: Vec<_> = expected.iter().map(|v| Rc::new(v.clone())).collect();
+        let expected_rc: Vec<_> = expected.iter().map(|v| Rc::new(*v)).collect();
         assert!(has_deleted);
         assert_eq!(
             expected_rc, actual,
@@ -1,26 +1,114 @@
 use super::{RootNode, Tree};
-use std::{collections::VecDeque, rc::Rc};
-pub struct IterShared<T> {
-    pub(super) nodes: VecDeque<RootNode<T>>,
+use crate::node_ref::{NodeRef, RcRefCell};
+use alloc::{collections::VecDeque, vec::Vec};
+pub struct IterShared<T, P: NodeRef = RcRefCell> {
+    pub(super) nodes: VecDeque<RootNode<T, P>>,
 }
 
-impl<T> Tree<T> {
-    pub fn iter_shared(&self) -> IterShared<T> {
+/// Iterates from the smallest to the largest value.
+pub struct IterInOrder<T, P: NodeRef = RcRefCell> {
+    pub(super) ancestors: Vec<RootNode<T, P>>,
+}
+
+/// Iterates from the largest to the smallest value.
+pub struct IterReverseOrder<T, P: NodeRef = RcRefCell> {
+    pub(super) ancestors: Vec<RootNode<T, P>>,
+}
+
+impl<T, P: NodeRef> Tree<T, P> {
+    /// Returns the values from the smallest to the largest.
+    /// ```
+    /// use tree_of_madness::build_tree;
+    ///
+    /// let tree = build_tree![10, 3, 16, 4];
+    /// let values: Vec<_> = tree.iter_in_order().map(|value| *value).collect();
+    ///
+    /// assert_eq!(values, vec![3, 4, 10, 16]);
+    /// ```
+    pub fn iter_in_order(&self) -> IterInOrder<T, P> {
+        let mut iter = IterInOrder {
+            ancestors: Vec::new(),
+        };
+        iter.push_left_path(self.root.clone());
+
+        iter
+    }
+
+    /// Returns the values from the largest to the smallest.
+    /// ```
+    /// use tree_of_madness::build_tree;
+    ///
+    /// let tree = build_tree![10, 3, 16, 4];
+    /// let values: Vec<_> = tree.iter_reverse_order().map(|value| *value).collect();
+    ///
+    /// assert_eq!(values, vec![16, 10, 4, 3]);
+    /// ```
+    pub fn iter_reverse_order(&self) -> IterReverseOrder<T, P> {
+        let mut iter = IterReverseOrder {
+            ancestors: Vec::new(),
+        };
+        iter.push_right_path(self.root.clone());
+
+        iter
+    }
+
+    /// Returns up to n values from the smallest on. Only these values are visited.
+    pub fn take_smallest(&self, n: usize) -> Vec<P::Value<T>> {
+        self.iter_in_order().take(n).collect()
+    }
+
+    /// Returns up to n values from the largest on. Only these values are visited.
+    /// ```
+    /// use tree_of_madness::build_tree;
+    ///
+    /// let tree = build_tree![5, 1, 9, 7, 3];
+    /// let largest: Vec<_> = tree.take_largest(2).iter().map(|value| **value).collect();
+    ///
+    /// assert_eq!(largest, vec![9, 7]);
+    /// ```
+    pub fn take_largest(&self, n: usize) -> Vec<P::Value<T>> {
+        self.iter_reverse_order().take(n).collect()
+    }
+
+    /// Number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.iter_shared().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the values level by level from the root, each level from left to right.
+    /// The order only depends on the shape of the tree, so it is the same for every run.
+    /// A tree holds every value once, added duplicates are not repeated.
+    /// ```
+    /// use tree_of_madness::build_tree;
+    ///
+    /// //      35
+    /// //   10    46
+    /// //  4     38
+    /// let tree = build_tree![35, 10, 4, 46, 38, 10, 4];
+    /// let values: Vec<_> = tree.iter_shared().map(|value| *value).collect();
+    ///
+    /// assert_eq!(values, vec![35, 10, 46, 4, 38]);
+    /// ```
+    pub fn iter_shared(&self) -> IterShared<T, P> {
         let mut deque: VecDeque<_> = VecDeque::new();
 
         if let Some(root) = self.root.as_ref() {
-            deque.push_back(Rc::clone(root));
+            deque.push_back(root.clone());
         };
 
         IterShared { nodes: deque }
     }
 }
 
-impl<T> Iterator for IterShared<T> {
-    type Item = Rc<T>;
+impl<T, P: NodeRef> Iterator for IterShared<T, P> {
+    type Item = P::Value<T>;
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(next) = self.nodes.pop_front() {
-            let next_borrow = next.borrow();
+            let next_borrow = P::borrow(&next);
             if let Some(left) = next_borrow.get_left_child_shared() {
                 self.nodes.push_back(left);
             };
@@ -35,3 +123,43 @@ impl<T> Iterator for IterShared<T> {
         None
     }
 }
+
+impl<T, P: NodeRef> IterInOrder<T, P> {
+    fn push_left_path(&mut self, mut current: Option<RootNode<T, P>>) {
+        while let Some(node) = current {
+            current = P::borrow(&node).get_left_child_shared();
+            self.ancestors.push(node);
+        }
+    }
+}
+
+impl<T, P: NodeRef> Iterator for IterInOrder<T, P> {
+    type Item = P::Value<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.ancestors.pop()?;
+        let next_borrow = P::borrow(&next);
+        self.push_left_path(next_borrow.get_right_child_shared());
+
+        Some(next_borrow.get_shared_value())
+    }
+}
+
+impl<T, P: NodeRef> IterReverseOrder<T, P> {
+    fn push_right_path(&mut self, mut current: Option<RootNode<T, P>>) {
+        while let Some(node) = current {
+            current = P::borrow(&node).get_right_child_shared();
+            self.ancestors.push(node);
+        }
+    }
+}
+
+impl<T, P: NodeRef> Iterator for IterReverseOrder<T, P> {
+    type Item = P::Value<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.ancestors.pop()?;
+        let next_borrow = P::borrow(&next);
+        self.push_right_path(next_borrow.get_left_child_shared());
+
+        Some(next_borrow.get_shared_value())
+    }
+}
@@ -0,0 +1,90 @@
+use super::{RootNode, Tree};
+use crate::node_ref::NodeRef;
+use alloc::{vec, vec::Vec};
+
+/// Overview of the shape of a tree, see [`Tree::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TreeStats {
+    /// Number of values.
+    pub len: usize,
+    /// Number of levels, 0 for an empty tree and 1 for only a root.
+    pub height: usize,
+    /// Number of nodes without children.
+    pub leaves: usize,
+    /// True if the heights of the left and right side differ by at most 1 at every node.
+    pub is_balanced: bool,
+}
+
+/// Height and balance of an already visited subtree.
+#[derive(Clone, Copy)]
+struct Subtree {
+    height: usize,
+    is_balanced: bool,
+}
+
+const EMPTY: Subtree = Subtree {
+    height: 0,
+    is_balanced: true,
+};
+
+impl<T, P: NodeRef> Tree<T, P> {
+    /// Returns length, height, leaves and balance computed in one walk over the tree.
+    /// ```
+    /// use tree_of_madness::build_tree;
+    ///
+    /// //   10
+    /// //  3  16
+    /// let stats = build_tree![10, 3, 16].stats();
+    ///
+    /// assert_eq!((stats.len, stats.height, stats.leaves), (3, 2, 2));
+    /// assert!(stats.is_balanced);
+    /// ```
+    pub fn stats(&self) -> TreeStats {
+        let Some(root) = self.root.clone() else {
+            return TreeStats {
+                is_balanced: true,
+                ..TreeStats::default()
+            };
+        };
+
+        let mut stats = TreeStats::default();
+        // A node is visited a second time after both of its children, so their subtrees are
+        // on top of `visited`. Without recursion a degenerated tree can not overflow the stack.
+        let mut pending: Vec<(RootNode<T, P>, bool)> = vec![(root, false)];
+        let mut visited: Vec<Subtree> = Vec::new();
+
+        while let Some((node, children_visited)) = pending.pop() {
+            let (left, right) = {
+                let node = P::borrow(&node);
+                (node.get_left_child_shared(), node.get_right_child_shared())
+            };
+
+            if !children_visited {
+                let has_children = left.is_some() || right.is_some();
+                pending.push((node, true));
+                pending.extend(left.map(|left| (left, false)));
+                pending.extend(right.map(|right| (right, false)));
+                if !has_children {
+                    stats.leaves += 1;
+                }
+                continue;
+            }
+
+            // The left child was pushed before the right, so its subtree is done last.
+            let left = left.map_or(EMPTY, |_| visited.pop().unwrap_or(EMPTY));
+            let right = right.map_or(EMPTY, |_| visited.pop().unwrap_or(EMPTY));
+            stats.len += 1;
+            visited.push(Subtree {
+                height: left.height.max(right.height) + 1,
+                is_balanced: left.is_balanced
+                    && right.is_balanced
+                    && left.height.abs_diff(right.height) <= 1,
+            });
+        }
+
+        let root = visited.pop().unwrap_or(EMPTY);
+        stats.height = root.height;
+        stats.is_balanced = root.is_balanced;
+        stats
+    }
+}
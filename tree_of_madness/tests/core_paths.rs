@@ -0,0 +1,14 @@
+//! Runs with std and with `--no-default-features`, where the library is `no_std`.
+use tree_of_madness::build_tree;
+
+#[test]
+fn should_add_find_and_delete_without_std() {
+    let mut tree = build_tree![35, 10, 4, 8, 46, 38];
+
+    assert!(tree.contains(&38));
+    assert!(tree.delete(&10));
+    assert!(!tree.contains(&10));
+
+    let values: Vec<_> = tree.iter_shared().map(|value| *value).collect();
+    assert_eq!(values, vec![35, 4, 46, 8, 38]);
+}
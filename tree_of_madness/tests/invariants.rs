@@ -0,0 +1,46 @@
+//! Random sequences of operations on a tree compared against a BTreeSet.
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+use tree_of_madness::tree::Tree;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(i32),
+    Delete(i32),
+    Contains(i32),
+}
+
+/// A small range of values leads to many duplicates and deletions of existing values.
+fn operation() -> impl Strategy<Value = Operation> {
+    let value = -20..20;
+    prop_oneof![
+        value.clone().prop_map(Operation::Add),
+        value.clone().prop_map(Operation::Delete),
+        value.prop_map(Operation::Contains),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn should_behave_like_btree_set(operations in prop::collection::vec(operation(), 0..100)) {
+        let mut tree = Tree::new();
+        let mut shadow = BTreeSet::new();
+
+        for operation in operations {
+            match operation {
+                Operation::Add(value) => prop_assert_eq!(tree.add(value), shadow.insert(value)),
+                Operation::Delete(value) => {
+                    prop_assert_eq!(tree.delete(&value), shadow.remove(&value))
+                }
+                Operation::Contains(value) => {
+                    prop_assert_eq!(tree.contains(&value), shadow.contains(&value))
+                }
+            }
+
+            let in_order: Vec<_> = tree.iter_in_order().map(|value| *value).collect();
+            prop_assert!(in_order.windows(2).all(|pair| pair[0] < pair[1]), "Not sorted: {:?}", in_order);
+            prop_assert_eq!(tree.len(), shadow.len());
+            prop_assert_eq!(in_order, shadow.iter().copied().collect::<Vec<_>>());
+        }
+    }
+}